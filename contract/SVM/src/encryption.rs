@@ -0,0 +1,181 @@
+//! Client-side encryption of proofs to a task's reviewers.
+//!
+//! The proof is sealed with AES-256-GCM-SIV under a random content key.
+//! That key is wrapped for each reviewer under a key derived from an
+//! X25519 exchange between a one-time ephemeral key and the Montgomery
+//! form of the reviewer's ed25519 wallet key.
+
+use aes_gcm_siv::{
+    aead::{Aead, NewAead},
+    Aes256GcmSiv, Key, Nonce,
+};
+use curve25519_dalek::{
+    constants::X25519_BASEPOINT, edwards::CompressedEdwardsY, montgomery::MontgomeryPoint,
+    scalar::Scalar,
+};
+use rand::RngCore;
+use sha2::{Digest, Sha256, Sha512};
+use solana_program::pubkey::Pubkey;
+use thiserror::Error;
+
+use crate::state::{EncryptedProof, WrappedKey};
+
+#[derive(Error, Debug, Copy, Clone, PartialEq)]
+pub enum EncryptionError {
+    #[error("Invalid Reviewer Key")]
+    InvalidReviewerKey,
+    #[error("Not A Recipient")]
+    NotARecipient,
+    #[error("Encryption Failed")]
+    EncryptionFailed,
+    #[error("Decryption Failed")]
+    DecryptionFailed,
+}
+
+/// Encrypts `proof` so that only `reviewers` can read it
+pub fn encrypt_proof(
+    proof: &[u8],
+    reviewers: &[Pubkey],
+) -> Result<EncryptedProof, EncryptionError> {
+    let mut rng = rand::thread_rng();
+    let mut content_key = [0u8; 32];
+    let mut nonce = [0u8; 12];
+    let mut ephemeral_secret = [0u8; 32];
+    rng.fill_bytes(&mut content_key);
+    rng.fill_bytes(&mut nonce);
+    rng.fill_bytes(&mut ephemeral_secret);
+    let ephemeral_secret = clamp(ephemeral_secret);
+    let ephemeral_key = X25519_BASEPOINT * ephemeral_secret;
+
+    let ciphertext = Aes256GcmSiv::new(Key::from_slice(&content_key))
+        .encrypt(Nonce::from_slice(&nonce), proof)
+        .map_err(|_| EncryptionError::EncryptionFailed)?;
+
+    let wrapped_keys = reviewers
+        .iter()
+        .map(|reviewer| {
+            let shared = to_montgomery(reviewer)? * ephemeral_secret;
+            let wrapped = wrapping_cipher(&shared, &ephemeral_key, reviewer)
+                .encrypt(Nonce::from_slice(&[0u8; 12]), content_key.as_ref())
+                .map_err(|_| EncryptionError::EncryptionFailed)?;
+            let mut key = [0u8; 48];
+            key.copy_from_slice(&wrapped);
+            Ok(WrappedKey {
+                reviewer: *reviewer,
+                key,
+            })
+        })
+        .collect::<Result<Vec<_>, EncryptionError>>()?;
+
+    Ok(EncryptedProof {
+        ciphertext,
+        nonce,
+        ephemeral_key: ephemeral_key.to_bytes(),
+        wrapped_keys,
+    })
+}
+
+/// Decrypts a proof with a reviewer's 64-byte Solana keypair
+pub fn decrypt_proof(
+    encrypted: &EncryptedProof,
+    reviewer_keypair: &[u8; 64],
+) -> Result<Vec<u8>, EncryptionError> {
+    let reviewer = Pubkey::try_from(&reviewer_keypair[32..])
+        .map_err(|_| EncryptionError::InvalidReviewerKey)?;
+    let wrapped = encrypted
+        .key_for(&reviewer)
+        .ok_or(EncryptionError::NotARecipient)?;
+
+    // The X25519 secret of an ed25519 keypair is its clamped expanded seed
+    let expanded = Sha512::digest(&reviewer_keypair[..32]);
+    let mut secret = [0u8; 32];
+    secret.copy_from_slice(&expanded[..32]);
+    let ephemeral_key = MontgomeryPoint(encrypted.ephemeral_key);
+    let shared = ephemeral_key * clamp(secret);
+
+    let content_key = wrapping_cipher(&shared, &ephemeral_key, &reviewer)
+        .decrypt(Nonce::from_slice(&[0u8; 12]), wrapped.key.as_ref())
+        .map_err(|_| EncryptionError::DecryptionFailed)?;
+    Aes256GcmSiv::new(Key::from_slice(&content_key))
+        .decrypt(Nonce::from_slice(&encrypted.nonce), encrypted.ciphertext.as_ref())
+        .map_err(|_| EncryptionError::DecryptionFailed)
+}
+
+fn clamp(mut bytes: [u8; 32]) -> Scalar {
+    bytes[0] &= 248;
+    bytes[31] &= 127;
+    bytes[31] |= 64;
+    Scalar::from_bits(bytes)
+}
+
+fn to_montgomery(key: &Pubkey) -> Result<MontgomeryPoint, EncryptionError> {
+    CompressedEdwardsY(key.to_bytes())
+        .decompress()
+        .map(|point| point.to_montgomery())
+        .ok_or(EncryptionError::InvalidReviewerKey)
+}
+
+/// Each wrapping key is used once, so a fixed nonce is safe
+fn wrapping_cipher(
+    shared: &MontgomeryPoint,
+    ephemeral_key: &MontgomeryPoint,
+    reviewer: &Pubkey,
+) -> Aes256GcmSiv {
+    let key = Sha256::new()
+        .chain_update(b"bounty-board-proof-key")
+        .chain_update(shared.as_bytes())
+        .chain_update(ephemeral_key.as_bytes())
+        .chain_update(reviewer.as_ref())
+        .finalize();
+    Aes256GcmSiv::new(Key::from_slice(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+
+    /// A Solana keypair: the ed25519 seed followed by its public key
+    fn keypair(seed: u8) -> [u8; 64] {
+        let mut keypair = [seed; 64];
+        let expanded = Sha512::digest(&keypair[..32]);
+        let mut secret = [0u8; 32];
+        secret.copy_from_slice(&expanded[..32]);
+        let public = (ED25519_BASEPOINT_POINT * clamp(secret)).compress();
+        keypair[32..].copy_from_slice(public.as_bytes());
+        keypair
+    }
+
+    fn pubkey(keypair: &[u8; 64]) -> Pubkey {
+        Pubkey::try_from(&keypair[32..]).unwrap()
+    }
+
+    #[test]
+    fn reviewers_decrypt_proof() {
+        let first = keypair(1);
+        let second = keypair(2);
+        let proof = b"https://example.com/proof";
+
+        let encrypted = encrypt_proof(proof, &[pubkey(&first), pubkey(&second)]).unwrap();
+        assert_eq!(encrypted.wrapped_keys.len(), 2);
+        assert_eq!(decrypt_proof(&encrypted, &first).unwrap(), proof);
+        assert_eq!(decrypt_proof(&encrypted, &second).unwrap(), proof);
+    }
+
+    #[test]
+    fn others_cannot_decrypt_proof() {
+        let reviewer = keypair(1);
+        let mut encrypted = encrypt_proof(b"proof", &[pubkey(&reviewer)]).unwrap();
+
+        assert_eq!(
+            decrypt_proof(&encrypted, &keypair(3)),
+            Err(EncryptionError::NotARecipient)
+        );
+
+        encrypted.ciphertext[0] ^= 1;
+        assert_eq!(
+            decrypt_proof(&encrypted, &reviewer),
+            Err(EncryptionError::DecryptionFailed)
+        );
+    }
+}
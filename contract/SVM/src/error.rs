@@ -33,6 +33,162 @@ pub enum BountyBoardError {
     AlreadyApproved,
     #[error("No Submission Found")]
     NoSubmissionFound,
+    #[error("Not Eligible")]
+    NotEligible,
+    #[error("Already Voted")]
+    AlreadyVoted,
+    #[error("Submission Not Pending")]
+    SubmissionNotPending,
+    #[error("Invalid Approval Threshold")]
+    InvalidApprovalThreshold,
+    #[error("Reviewer Already Exists")]
+    ReviewerAlreadyExists,
+    #[error("Not An Arbiter")]
+    NotAnArbiter,
+    #[error("Submission Not Rejected")]
+    SubmissionNotRejected,
+    #[error("Dispute Already Resolved")]
+    DisputeAlreadyResolved,
+    #[error("Invalid Deposit Account")]
+    InvalidDepositAccount,
+    #[error("Reward Exceeds Cap")]
+    RewardExceedsCap,
+    #[error("Invalid Reward Tier")]
+    InvalidRewardTier,
+    #[error("Invalid Contributor Shares")]
+    InvalidContributorShares,
+    #[error("Invalid Prize Shares")]
+    InvalidPrizeShares,
+    #[error("Invalid Task Kind")]
+    InvalidTaskKind,
+    #[error("Contest Not Ended")]
+    ContestNotEnded,
+    #[error("Invalid Ranking")]
+    InvalidRanking,
+    #[error("Nothing To Claim")]
+    NothingToClaim,
+    #[error("Invalid Token Account")]
+    InvalidTokenAccount,
+    #[error("Unknown Reward Mint")]
+    UnknownRewardMint,
+    #[error("Reward Mint Already Added")]
+    RewardMintAlreadyAdded,
+    #[error("Invalid Vesting Schedule")]
+    InvalidVestingSchedule,
+    #[error("Vesting Already Clawed Back")]
+    VestingAlreadyClawedBack,
+    #[error("Invalid Milestone Shares")]
+    InvalidMilestoneShares,
+    #[error("Invalid Recurrence")]
+    InvalidRecurrence,
+    #[error("Cycle Not Ended")]
+    CycleNotEnded,
+    #[error("No Cycles Remaining")]
+    NoCyclesRemaining,
+    #[error("Task Not Expired")]
+    TaskNotExpired,
+    #[error("Task Expired")]
+    TaskExpired,
+    #[error("Invalid Config Version")]
+    InvalidConfigVersion,
+    #[error("Join Not Allowed")]
+    JoinNotAllowed,
+    #[error("Too Many Reviewers")]
+    TooManyReviewers,
+    #[error("Submission Limit Reached")]
+    SubmissionLimitReached,
+    #[error("Reviewer Is Participant")]
+    ReviewerIsParticipant,
+    #[error("Name Too Long")]
+    NameTooLong,
+    #[error("Description Too Long")]
+    DescriptionTooLong,
+    #[error("Image Url Too Long")]
+    ImageUrlTooLong,
+    #[error("Invalid Image Url")]
+    InvalidImageUrl,
+    #[error("Metadata Too Long")]
+    MetadataTooLong,
+    #[error("Proof Too Long")]
+    ProofTooLong,
+    #[error("Comment Too Long")]
+    CommentTooLong,
+    #[error("Content Uri Too Long")]
+    ContentUriTooLong,
+    #[error("Invalid Content Uri")]
+    InvalidContentUri,
+    #[error("Reviewer Keys Mismatch")]
+    ReviewerKeysMismatch,
+    #[error("Submission Not Committed")]
+    SubmissionNotCommitted,
+    #[error("Commitment Mismatch")]
+    CommitmentMismatch,
+    #[error("Account Already Migrated")]
+    AccountAlreadyMigrated,
+    #[error("Task Not Finished")]
+    TaskNotFinished,
+    #[error("Submission Not Finalized")]
+    SubmissionNotFinalized,
+    #[error("Task Has Open Submissions")]
+    TaskHasOpenSubmissions,
+    #[error("Program Config Already Initialized")]
+    ProgramConfigAlreadyInitialized,
+    #[error("Invalid Program Config")]
+    InvalidProgramConfig,
+    #[error("Not Upgrade Authority")]
+    NotUpgradeAuthority,
+    #[error("Not Program Admin")]
+    NotProgramAdmin,
+    #[error("Program Paused")]
+    ProgramPaused,
+    #[error("Feature Disabled")]
+    FeatureDisabled,
+    #[error("Invalid Fee Recipient")]
+    InvalidFeeRecipient,
+    #[error("Too Many Reward Mints")]
+    TooManyRewardMints,
+    #[error("Insufficient Reservation")]
+    InsufficientReservation,
+    #[error("Board Has Reserved Funds")]
+    BoardHasReservedFunds,
+    #[error("Invalid Account Kind")]
+    InvalidAccountKind,
+    #[error("Account Not Migrated")]
+    AccountNotMigrated,
+    #[error("Slash Destination Required")]
+    SlashDestinationRequired,
+    #[error("Too Many Arbiters")]
+    TooManyArbiters,
+    #[error("Too Many Contributors")]
+    TooManyContributors,
+    #[error("Too Many Reward Tiers")]
+    TooManyRewardTiers,
+    #[error("Too Many Allowlisted")]
+    TooManyAllowlisted,
+    #[error("Too Many Prizes")]
+    TooManyPrizes,
+    #[error("Too Many Milestones")]
+    TooManyMilestones,
+    #[error("Account Too Small")]
+    AccountTooSmall,
+    #[error("Submissions Untracked")]
+    SubmissionsUntracked,
+    #[error("Submission Not Approved")]
+    SubmissionNotApproved,
+    #[error("Clawback Not Upheld")]
+    ClawbackNotUpheld,
+    #[error("Blind Voting Required")]
+    BlindVotingRequired,
+    #[error("Blind Voting Disabled")]
+    BlindVotingDisabled,
+    #[error("Reveal Not Open")]
+    RevealNotOpen,
+    #[error("Commits Closed")]
+    CommitsClosed,
+    #[error("Submission From Past Cycle")]
+    SubmissionFromPastCycle,
+    #[error("Deadline Required")]
+    DeadlineRequired,
 }
 
 impl From<BountyBoardError> for ProgramError {
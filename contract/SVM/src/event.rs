@@ -0,0 +1,152 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::state::{AccountKind, MemberRole, RewardAsset, SubmissionStatus, TaskKind};
+
+/// Events logged by the program as Borsh-encoded `Program data:` entries,
+/// so indexers can follow state changes without re-reading accounts
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub enum BountyBoardEvent {
+    BoardCreated {
+        board: Pubkey,
+        creator: Pubkey,
+        reward_asset: RewardAsset,
+        reward_token: Pubkey,
+    },
+    TaskCreated {
+        board: Pubkey,
+        task: Pubkey,
+        kind: TaskKind,
+        reward_mint: Pubkey,
+        reward_amount: u64,
+        max_completions: u64,
+        deadline: i64,
+    },
+    TaskRolled {
+        task: Pubkey,
+        cycle: u32,
+        deadline: i64,
+    },
+    /// `released` is the reservation returned to the board
+    TaskExpired {
+        task: Pubkey,
+        released: u64,
+    },
+    ReviewerAdded {
+        task: Pubkey,
+        reviewer: Pubkey,
+    },
+    MemberJoined {
+        board: Pubkey,
+        user: Pubkey,
+    },
+    MemberUpdated {
+        board: Pubkey,
+        user: Pubkey,
+        role: MemberRole,
+        reputation: u64,
+    },
+    ProofSubmitted {
+        task: Pubkey,
+        submission: Pubkey,
+        submitter: Pubkey,
+        deposit: u64,
+    },
+    ProofRevealed {
+        task: Pubkey,
+        submission: Pubkey,
+        submitter: Pubkey,
+        committed_at: i64,
+    },
+    /// A reviewer's vote and the submission's status after tallying it
+    SubmissionReviewed {
+        task: Pubkey,
+        submission: Pubkey,
+        reviewer: Pubkey,
+        vote: i8,
+        status: SubmissionStatus,
+        approved_amount: u64,
+    },
+    VoteCommitted {
+        task: Pubkey,
+        submission: Pubkey,
+        reviewer: Pubkey,
+    },
+    SubmissionSelfChecked {
+        task: Pubkey,
+        submission: Pubkey,
+        submitter: Pubkey,
+        amount: u64,
+    },
+    ContestFinalized {
+        task: Pubkey,
+        ranking: Vec<Pubkey>,
+        total_paid: u64,
+    },
+    RewardPaid {
+        task: Pubkey,
+        recipient: Pubkey,
+        destination: Pubkey,
+        amount: u64,
+    },
+    /// `returned` is the unvested amount given back to the board
+    VestingClawedBack {
+        submission: Pubkey,
+        returned: u64,
+    },
+    RewardMintAdded {
+        board: Pubkey,
+        mint: Pubkey,
+        vault: Pubkey,
+    },
+    /// `amount` is what the board received, after any transfer fee
+    TokensPledged {
+        board: Pubkey,
+        reward_mint: Pubkey,
+        amount: u64,
+    },
+    DisputeOpened {
+        submission: Pubkey,
+        submitter: Pubkey,
+        bond: u64,
+    },
+    DisputeResolved {
+        submission: Pubkey,
+        arbiter: Pubkey,
+        overturned: bool,
+    },
+    BoardClosed {
+        board: Pubkey,
+    },
+    TaskClosed {
+        board: Pubkey,
+        task: Pubkey,
+        completions: u64,
+    },
+    SubmissionClosed {
+        task: Pubkey,
+        submission: Pubkey,
+        status: SubmissionStatus,
+    },
+    AccountMigrated {
+        account: Pubkey,
+        kind: AccountKind,
+    },
+    ProgramConfigUpdated {
+        admin: Pubkey,
+        paused: bool,
+    },
+}
+
+impl BountyBoardEvent {
+    /// Logs the event for indexers
+    pub fn emit(&self) -> ProgramResult {
+        sol_log_data(&[&self.try_to_vec()?]);
+        Ok(())
+    }
+
+    /// Decodes an event from the bytes of a `Program data:` log entry
+    pub fn decode(data: &[u8]) -> Result<Self, std::io::Error> {
+        Self::try_from_slice(data)
+    }
+}
@@ -1,95 +1,603 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 
+use crate::state::{
+    AccountKind, BoardConfig, ContentRef, Contributor, DepositKind, Eligibility, EncryptedProof,
+    ExpiryPolicy, FeatureFlags, MemberRole, ProgramLimits, ProtocolFees, Recurrence, RewardAsset,
+    RewardChoice, TaskConfig, TaskKind, VestingSchedule,
+};
+
+/// Every instruction but `InitializeProgramConfig` expects the program
+/// config account (PDA) ahead of the accounts listed for it, writable for
+/// the admin instructions. Only those run while the program is paused.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum BountyBoardInstruction {
-    /// Initialize a new board
+    /// Initialize a new board. `metadata` is free-form data for clients;
+    /// `config` holds the rules the program enforces. The board's token
+    /// accounts are owned by its vault authority, the PDA
+    /// `[b"authority", board]`.
     /// Accounts expected:
-    /// 0. `[signer]` The board creator
+    /// 0. `[signer, writable]` The board creator
     /// 1. `[writable]` The board account
-    /// 2. `[]` The reward token mint account
+    /// 2. `[]` The reward token mint account, owned by the SPL Token or
+    ///    Token-2022 program (ignored for SOL boards)
     /// 3. `[]` The system program
     /// 4. `[]` The rent sysvar
+    ///
+    /// Token boards additionally expect:
+    /// 5. `[writable]` The vault authority's associated token account for
+    ///    the reward token, created as the board's token account
+    /// 6. `[]` The board's vault authority (PDA)
+    /// 7. `[]` The token program owning the mint
+    /// 8. `[]` The associated token account program
+    ///
+    /// SOL boards additionally expect:
+    /// 5. `[writable]` The board vault (PDA)
+    ///
+    /// While the program charges a board fee, the accounts end with:
+    /// - `[writable]` The fee recipient
     InitializeBoard {
         name: String,
         description: String,
         img: String,
-        config: String,
+        metadata: String,
+        config: BoardConfig,
+        reward_asset: RewardAsset,
     },
 
-    /// Create a new task in a board
+    /// Create a new task in a board, reserving its rewards from the board's
+    /// available pledges. Contest tasks need a deadline, a review grace
+    /// period to be ranked in before they can expire, and place shares
+    /// summing to 10000 basis points; `reward_amount` is their prize pool.
+    /// `reward_mint` selects a pool added with `AddRewardMint`, or the
+    /// board's own reward asset when left at the default key.
+    /// With a `vesting` schedule, approved rewards are released linearly
+    /// and claimed with `ClaimVested`; such tasks cannot enable self-checks.
+    /// `metadata` is free-form data for clients; `config` holds the rules
+    /// the program enforces.
+    /// Milestone tasks split `reward_amount` across milestones by
+    /// `milestone_shares_bps`, summing to 10000 basis points.
+    /// A `recurrence` reopens a standard task with a deadline after each
+    /// cycle through `RollTask`; only the first cycle is reserved here.
+    /// Reviewers have `review_grace_period` seconds after the deadline
+    /// before `ExpireTask` resolves pending submissions by `expiry_policy`,
+    /// which may only approve them on standard tasks without vesting.
+    /// A `description_ref` keeps the full description off-chain, leaving
+    /// `description` free for a short summary.
     /// Accounts expected:
     /// 0. `[signer]` The task creator (must be board creator)
     /// 1. `[writable]` The board account
     /// 2. `[writable]` The task account
+    ///
+    /// While the program charges a task fee, additionally expects:
+    /// 3. `[writable]` The fee recipient
+    /// 4. `[]` The system program
     CreateTask {
         name: String,
         description: String,
         deadline: i64,
         max_completions: u64,
         reward_amount: u64,
-        config: String,
-        allow_self_check: bool,
+        metadata: String,
+        config: TaskConfig,
+        eligibility: Eligibility,
+        approval_threshold: u8,
+        submission_deposit: u64,
+        deposit_kind: DepositKind,
+        reward_tiers: Vec<u64>,
+        kind: TaskKind,
+        prize_shares_bps: Vec<u16>,
+        reward_mint: Pubkey,
+        vesting: Option<VestingSchedule>,
+        milestone_shares_bps: Vec<u16>,
+        recurrence: Option<Recurrence>,
+        review_grace_period: i64,
+        expiry_policy: ExpiryPolicy,
+        description_ref: Option<ContentRef>,
     },
 
-    /// Join a board as a member
+    /// Open the next cycle of a recurring task once the current cycle's
+    /// deadline and review grace period have passed, resetting its
+    /// completions, moving its deadline by one period and reserving the
+    /// cycle's rewards. Reservations of the finished cycle's unfilled
+    /// completions are released; its pending submissions can no longer be
+    /// reviewed and are rejected by `ExpireTask`.
+    /// Anyone may roll a task.
+    /// Accounts expected:
+    /// 0. `[writable]` The board account
+    /// 1. `[writable]` The task account
+    RollTask,
+
+    /// Expire a task once its deadline and review grace period have passed
+    /// and no cycles remain, resolving the given pending submissions by the
+    /// task's expiry policy and returning its remaining reservation to the
+    /// board. Deposits of resolved submissions are refunded. Unrevealed
+    /// commitments are always rejected.
+    /// A recurring task with cycles remaining expires when the board can
+    /// no longer fund its next cycle, ending the recurrence.
+    /// Expiring an expired task again rejects pending or committed
+    /// submissions left out before, refunding their deposits; a recurring
+    /// task not yet expired rejects those of its earlier cycles.
+    /// Anyone may expire a task.
     /// Accounts expected:
-    /// 0. `[signer]` The user joining the board
+    /// 0. `[writable]` The board account
+    /// 1. `[writable]` The task account
+    ///
+    /// Then, for every pending or committed submission to resolve:
+    /// - `[writable]` The submission account
+    /// - `[writable]` The submitter's wallet, if it holds a lamport deposit
+    ExpireTask,
+
+    /// Add a reviewer to a task, up to the board's reviewer limit
+    /// Accounts expected:
+    /// 0. `[signer]` The task creator
+    /// 1. `[writable]` The task account
+    /// 2. `[]` The board account
+    AddReviewer {
+        reviewer: Pubkey,
+    },
+
+    /// Join a board as a member. A member account from before member
+    /// records existed is converted in place, at the user's expense.
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The user joining the board
     /// 1. `[writable]` The board account
+    /// 2. `[writable]` The member account (PDA)
+    /// 3. `[]` The system program
+    ///
+    /// Boards requiring creator approval additionally expect:
+    /// 4. `[signer]` The board creator
     JoinBoard,
 
-    /// Submit proof for a task
+    /// Update a member's role and reputation
     /// Accounts expected:
-    /// 0. `[signer]` The submitter
+    /// 0. `[signer]` The board creator
+    /// 1. `[]` The board account
+    /// 2. `[writable]` The member account (PDA)
+    UpdateMember {
+        role: MemberRole,
+        reputation: u64,
+    },
+
+    /// Designate another owner whose token accounts may receive the
+    /// member's rewards. The default key clears it.
+    /// Accounts expected:
+    /// 0. `[signer]` The member
+    /// 1. `[]` The board account
+    /// 2. `[writable]` The member account (PDA)
+    SetPayoutAddress {
+        payout_address: Pubkey,
+    },
+
+    /// Submit proof for a task, paying the task's submission deposit if any.
+    /// `contributors` optionally splits the reward by basis-point shares
+    /// summing to 10000; when empty the submitter receives it all.
+    /// A `proof_ref` points at proof kept off-chain instead of in `proof`.
+    /// An `encrypted_proof` must be wrapped for exactly the task's current
+    /// reviewers; reviewers added later cannot read it.
+    /// The submission account is created at
+    /// `[b"submission", task, num_submissions]`, the task's submission count
+    /// as little-endian u64 bytes.
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The submitter
     /// 1. `[writable]` The board account
     /// 2. `[writable]` The task account
-    /// 3. `[writable]` The submission account
+    /// 3. `[writable]` The submission account (PDA)
+    /// 4. `[]` The submitter's member account (PDA)
+    /// 5. `[]` The system program
+    ///
+    /// Reward token deposits additionally expect:
+    /// 6. `[writable]` The submitter's token account
+    /// 7. `[writable]` The board's token account, or the pool's vault
+    /// 8. `[]` The token program
+    /// 9. `[]` The task's reward mint
     SubmitProof {
         proof: String,
+        contributors: Vec<Contributor>,
+        proof_ref: Option<ContentRef>,
+        encrypted_proof: Option<EncryptedProof>,
+    },
+
+    /// Commit to a proof without publishing it, so it cannot be copied
+    /// before the submitter reveals it. The commitment is computed by
+    /// `Submission::commitment_for`; the submission is timestamped and its
+    /// deposit collected now, and reviewers order submissions by this time.
+    /// Accounts expected: as for `SubmitProof`
+    CommitProof {
+        commitment: [u8; 32],
+        contributors: Vec<Contributor>,
+    },
+
+    /// Reveal the proof of a committed submission, making it pending review
+    /// Accounts expected:
+    /// 0. `[signer]` The submitter
+    /// 1. `[]` The task account
+    /// 2. `[writable]` The submission account
+    RevealProof {
+        proof: String,
+        salt: [u8; 32],
+        proof_ref: Option<ContentRef>,
+        encrypted_proof: Option<EncryptedProof>,
     },
 
-    /// Review a submission
+    /// Replace the proof of a milestone task's submission with the work
+    /// for its current milestone
+    /// Accounts expected:
+    /// 0. `[signer]` The submitter
+    /// 1. `[]` The task account
+    /// 2. `[writable]` The submission account
+    SubmitMilestone {
+        proof: String,
+        proof_ref: Option<ContentRef>,
+        encrypted_proof: Option<EncryptedProof>,
+    },
+
+    /// Vote on a submission. Once the task's approval threshold is reached
+    /// the reward is credited to the submission's payees, who collect it
+    /// with `ClaimReward`; once the threshold becomes unreachable the
+    /// submission is rejected.
+    /// `status` is 1 to approve, -1 to reject and -2 to reject as spam.
+    /// Approvals carry a reward choice; the lowest approved amount is paid.
+    /// The deposit is refunded on approval or rejection, and slashed to
+    /// the board's slash destination when most rejections flagged spam.
+    /// Milestone tasks are voted on one milestone at a time with a full
+    /// reward choice, each approval paying that milestone's share. The
+    /// submission is approved after the final milestone; rejecting a later
+    /// milestone only reopens it for review.
+    /// Votes after the first review round are recorded at
+    /// `[b"vote", submission, reviewer, [review_round]]`.
+    /// Tasks with blind voting take votes through `CommitVote` and
+    /// `RevealVote` instead.
     /// Accounts expected:
     /// 0. `[signer]` The reviewer
     /// 1. `[writable]` The board account
     /// 2. `[writable]` The task account
     /// 3. `[writable]` The submission account
-    /// 4. `[writable]` The submitter's token account
-    /// 5. `[]` The token program
+    /// 4. `[writable]` The reviewer's vote record (PDA)
+    /// 5. `[]` The system program
+    ///
+    /// Finalizing a submission holding a deposit additionally expects:
+    /// 6. `[writable]` The submitter's wallet
+    /// 7. `[writable]` The slash destination (wallet or token account), or
+    ///    the board vault (PDA) when slashing lamports to a SOL board
+    /// 8. `[writable]` The board's token account, or the pool's vault
+    /// 9. `[]` The token program
+    /// 10. `[]` The task's reward mint
+    /// 11. `[]` The board's vault authority (PDA)
+    ///
+    /// Finally, approving a submission of a vesting task expects:
+    /// - `[writable]` The submission's vesting account (PDA), paid for
+    ///   by the reviewer
     ReviewSubmission {
         status: i8,
         review_comment: String,
+        reward: RewardChoice,
     },
 
-    /// Self-check submission with signature
+    /// Commit to a vote on a submission of a blind voting task without
+    /// revealing it. The commitment is computed by
+    /// `VoteRecord::commitment_for` and recorded in the reviewer's vote
+    /// record. Commits close once a vote of the review round is revealed.
     /// Accounts expected:
-    /// 0. `[signer]` The submitter
+    /// 0. `[signer, writable]` The reviewer
+    /// 1. `[]` The board account
+    /// 2. `[]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The reviewer's vote record (PDA)
+    /// 5. `[]` The system program
+    CommitVote {
+        commitment: [u8; 32],
+    },
+
+    /// Reveal a committed vote, tallying it as `ReviewSubmission` does.
+    /// Votes can be revealed once everyone reviewing the task at the
+    /// round's first commit has committed, or after the task's deadline.
+    /// Accounts expected: as for `ReviewSubmission`
+    RevealVote {
+        status: i8,
+        review_comment: String,
+        reward: RewardChoice,
+        salt: [u8; 32],
+    },
+
+    /// Self-check submission with signature. The submission account is
+    /// created as for `SubmitProof`.
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The submitter
     /// 1. `[writable]` The board account
     /// 2. `[writable]` The task account
-    /// 3. `[writable]` The submission account
-    /// 4. `[writable]` The submitter's token account
-    /// 5. `[]` The token program
+    /// 3. `[writable]` The submission account (PDA)
+    /// 4. `[writable]` The reward token account of the submitter or their payout
+    ///    address, or the wallet itself for lamport rewards
+    /// 5. `[writable]` The board's token account or the pool's vault, or the
+    ///    vault for lamport rewards
+    /// 6. `[]` The token program (unused for lamport rewards)
+    /// 7. `[]` The submitter's member account (PDA)
+    /// 8. `[]` The system program
+    /// 9. `[]` The task's reward mint (token rewards only)
+    /// 10. `[]` The board's vault authority (PDA) (token rewards only)
     SelfCheckSubmission {
         signature: Vec<u8>,
         check_data: String,
     },
 
-    /// Pledge tokens to a board
+    /// Rank a contest's submissions after its deadline, best first
+    /// Accounts expected:
+    /// 0. `[signer]` A task reviewer
+    /// 1. `[writable]` The task account
+    /// 2. `[]` The ranked submission accounts, one per place, best first
+    SubmitRanking,
+
+    /// Credit a contest's prize pool by place and return unawarded prizes
+    /// to the board
+    /// Accounts expected:
+    /// 0. `[signer]` A task reviewer
+    /// 1. `[writable]` The board account
+    /// 2. `[writable]` The task account
+    /// 3. `[writable]` The ranked submission accounts, in ranking order
+    FinalizeContest,
+
+    /// Claim the caller's approved payout of a submission, creating their
+    /// associated token account if it does not exist
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The payee
+    /// 1. `[]` The board account
+    /// 2. `[]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The payee's token account for the task's reward mint,
+    ///    or the receiving wallet for lamport rewards
+    /// 5. `[writable]` The board's token account or the pool's vault, or the
+    ///    vault for lamport rewards
+    ///
+    /// Token rewards additionally expect:
+    /// 6. `[]` The task's reward mint
+    /// 7. `[]` The token program
+    /// 8. `[]` The system program
+    /// 9. `[]` The associated token account program
+    /// 10. `[]` The board's vault authority (PDA)
+    ///
+    /// Finally, optionally:
+    /// - `[]` The payee's member account (PDA), allowing an existing
+    ///   account of their payout address
+    ClaimReward,
+
+    /// Release a vesting submission's newly vested reward to its payees
+    /// and claim the caller's payout
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The payee
+    /// 1. `[]` The board account
+    /// 2. `[]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The submission's vesting account (PDA)
+    ///
+    /// Followed by the accounts of `ClaimReward` from its destination on
+    ClaimVested,
+
+    /// Accept pledges in an additional mint, creating the vault authority's
+    /// associated token account for it as the pool's vault
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The board creator
+    /// 1. `[writable]` The board account
+    /// 2. `[]` The mint
+    /// 3. `[writable]` The vault authority's associated token account for the mint
+    /// 4. `[]` The board's vault authority (PDA)
+    /// 5. `[]` The token program owning the mint
+    /// 6. `[]` The system program
+    /// 7. `[]` The associated token account program
+    AddRewardMint,
+
+    /// Pledge tokens, or lamports for SOL boards, to a board
+    /// Only the amount received after any Token-2022 transfer fee is credited.
+    /// `reward_mint` selects a pool added with `AddRewardMint`, or the
+    /// board's own reward asset when left at the default key.
     /// Accounts expected:
     /// 0. `[signer]` The pledger
     /// 1. `[writable]` The board account
+    ///
+    /// Token boards and pools:
     /// 2. `[writable]` The pledger's token account
-    /// 3. `[writable]` The board's token account
+    /// 3. `[writable]` The board's token account, or the pool's vault
     /// 4. `[]` The token program
+    /// 5. `[]` The reward token mint
+    ///
+    /// SOL boards:
+    /// 2. `[writable]` The board vault (PDA)
+    /// 3. `[]` The system program
     PledgeTokens {
         amount: u64,
+        reward_mint: Pubkey,
     },
 
-    /// Close a board
+    /// Set the arbiters who resolve disputes and the bond required to open one.
+    /// Token boards need a slash destination to receive forfeited bonds.
     /// Accounts expected:
     /// 0. `[signer]` The board creator
     /// 1. `[writable]` The board account
+    SetArbiters {
+        arbiters: Vec<Pubkey>,
+        dispute_bond: u64,
+    },
+
+    /// Set where slashed submission deposits are sent.
+    /// The default key pledges them to the board, which only SOL boards
+    /// can do with lamport deposits; token boards must name a destination.
+    /// Accounts expected:
+    /// 0. `[signer]` The board creator
+    /// 1. `[writable]` The board account
+    SetSlashDestination {
+        destination: Pubkey,
+    },
+
+    /// Replace the board's enforced rules
+    /// Accounts expected:
+    /// 0. `[signer]` The board creator
+    /// 1. `[writable]` The board account
+    UpdateBoardConfig {
+        config: BoardConfig,
+    },
+
+    /// Dispute a rejected submission, depositing the board's dispute bond.
+    /// The task must still be able to pay the submission if overturned.
+    /// Accounts expected:
+    /// 0. `[signer]` The submitter
+    /// 1. `[]` The board account
+    /// 2. `[]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The dispute account (PDA)
+    /// 5. `[]` The system program
+    OpenDispute,
+
+    /// Resolve a dispute. Upholding the rejection pledges the bond to a SOL
+    /// board, or sends it to a token board's slash destination; overturning
+    /// it refunds the bond and credits the task reward. If the task can no
+    /// longer pay, overturning only refunds the bond.
+    /// Overturning a clawback dispute revokes the approval, allowing
+    /// `ClawbackVesting`; such disputes expect only accounts 0 to 4.
+    /// Accounts expected:
+    /// 0. `[signer]` The arbiter
+    /// 1. `[writable]` The board account
+    /// 2. `[writable]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The dispute account (PDA)
+    /// 5. `[writable]` The submitter's wallet
+    /// 6. `[writable]` The board vault (PDA) of SOL boards, or the slash
+    ///    destination
+    ///
+    /// Overturning the rejection of a vesting task additionally expects:
+    /// 7. `[writable]` The submission's vesting account (PDA), paid for
+    ///    by the arbiter
+    /// 8. `[]` The system program
+    ResolveDispute {
+        overturn: bool,
+        comment: String,
+    },
+
+    /// Dispute the approval of a vesting submission, for an arbiter to
+    /// decide whether its unvested reward may be clawed back
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The task creator or board creator
+    /// 1. `[]` The board account
+    /// 2. `[]` The task account
+    /// 3. `[]` The submission account
+    /// 4. `[writable]` The clawback dispute account
+    ///    (PDA `[b"clawback", submission]`)
+    /// 5. `[]` The system program
+    DisputeVesting,
+
+    /// Claw back the unvested part of a submission's reward once an arbiter
+    /// has overturned its approval in a clawback dispute. What has already
+    /// vested stays with the payees.
+    /// Accounts expected:
+    /// 0. `[signer]` The board creator or an arbiter
+    /// 1. `[writable]` The board account
+    /// 2. `[]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The submission's vesting account (PDA)
+    /// 5. `[]` The clawback dispute account (PDA)
+    ClawbackVesting {
+        comment: String,
+    },
+
+    /// Close a board, returning its pledges to the creator. Fails while any
+    /// task still holds a reservation; approved payouts stay claimable.
+    /// Accounts expected:
+    /// 0. `[signer]` The board creator
+    /// 1. `[writable]` The board account
+    /// 2. `[writable]` The board's token account, or vault for SOL boards
+    /// 3. `[writable]` The creator's token account, or wallet for SOL boards
+    /// 4. `[]` The token program (unused by SOL boards)
+    /// 5. `[]` The board's vault authority (PDA)
+    /// 6. `[]` The reward token mint (token boards only)
+    ///
+    /// Then, for every pool still holding pledges, in order:
+    /// - `[writable]` The pool's vault
+    /// - `[writable]` The creator's token account for the pool's mint
+    /// - `[]` The token program
+    /// - `[]` The pool's mint
     CloseBoard,
+
+    /// Close a finished task once all its submission accounts are closed,
+    /// returning its remaining reservation to the board and its rent to
+    /// the task creator. The board's archive keeps a summary.
+    /// Tasks migrated from the original program cannot be closed, as their
+    /// submission accounts were never counted.
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The task creator
+    /// 1. `[writable]` The board account
+    /// 2. `[writable]` The task account
+    CloseTask,
+
+    /// Close a finalized submission, returning its rent to the submitter.
+    /// The submitter may close it once finalized; anyone may once its task
+    /// has finished, which ends the chance to dispute a rejection.
+    /// Accounts expected:
+    /// 0. `[signer]` The closer
+    /// 1. `[writable]` The board account
+    /// 2. `[writable]` The task account
+    /// 3. `[writable]` The submission account
+    /// 4. `[writable]` The submitter's wallet
+    ///
+    /// Approved submissions of vesting tasks additionally expect:
+    /// 5. `[]` The vesting account (PDA), fully released or clawed back
+    CloseSubmission,
+
+    /// Rewrite a board, task or submission of the original program in the
+    /// current layout, reallocating it and topping up its rent from the
+    /// payer. Those accounts did not record what they belong to, so the
+    /// board creator vouches for it by paying. Unmigrated accounts are
+    /// readable as the board or task they are passed with, but must be
+    /// migrated before any instruction can update them.
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The board creator
+    /// 1. `[writable]` The account to migrate
+    /// 2. `[]` The system program
+    ///
+    /// Tasks and submissions additionally expect:
+    /// 3. `[]` The board account
+    ///
+    /// Submissions additionally expect:
+    /// 4. `[]` The task account, already migrated
+    MigrateAccount {
+        kind: AccountKind,
+    },
+
+    /// Create the program config once, signed by the program's upgrade
+    /// authority. It is the only instruction not preceded by the config.
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The upgrade authority
+    /// 1. `[writable]` The program config account (PDA)
+    /// 2. `[]` The program's program data account
+    /// 3. `[]` The system program
+    InitializeProgramConfig {
+        admin: Pubkey,
+        fees: ProtocolFees,
+        features: FeatureFlags,
+        limits: ProgramLimits,
+    },
+
+    /// Replace the program's fees, feature flags and limits
+    /// Accounts expected:
+    /// 0. `[signer]` The program admin
+    UpdateProgramConfig {
+        fees: ProtocolFees,
+        features: FeatureFlags,
+        limits: ProgramLimits,
+    },
+
+    /// Hand the program config over to a new admin
+    /// Accounts expected:
+    /// 0. `[signer]` The program admin
+    SetProgramAdmin {
+        admin: Pubkey,
+    },
+
+    /// Pause or resume every instruction but the admin instructions
+    /// Accounts expected:
+    /// 0. `[signer]` The program admin
+    SetPaused {
+        paused: bool,
+    },
 }
 
 impl BountyBoardInstruction {
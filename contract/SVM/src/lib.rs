@@ -1,8 +1,13 @@
+#![allow(clippy::too_many_arguments, clippy::field_reassign_with_default)]
+
 use solana_program::{
     account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey,
 };
 
+#[cfg(feature = "client")]
+pub mod encryption;
 pub mod error;
+pub mod event;
 pub mod instruction;
 pub mod processor;
 pub mod state;
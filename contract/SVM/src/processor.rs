@@ -1,21 +1,36 @@
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshDeserialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
-    program_pack::Pack,
+    bpf_loader_upgradeable,
     pubkey::Pubkey,
-    system_instruction,
+    system_instruction, system_program,
     sysvar::{clock::Clock, rent::Rent, Sysvar},
 };
-use spl_token::{instruction as token_instruction, state::Account as TokenAccount};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, instruction as associated_token_instruction,
+};
+use spl_token_2022::{
+    check_spl_token_program_account,
+    extension::StateWithExtensions,
+    instruction as token_instruction,
+    state::{Account as TokenAccount, Mint},
+};
 
 use crate::{
     error::BountyBoardError,
+    event::BountyBoardEvent,
     instruction::BountyBoardInstruction,
-    state::{Board, Submission, SubmissionStatus, Task},
+    state::{
+        legacy, AccountHeader, AccountKind, Board, BoardConfig, ContentRef, Contributor,
+        DepositKind, Dispute, Eligibility, EncryptedProof, ExpiryPolicy, FeatureFlags, JoinPolicy,
+        Member, MemberRole, ProgramConfig, ProgramLimits, ProtocolFees, Recurrence, ReviewPolicy,
+        RewardAsset, RewardChoice, SelfCheckPolicy, Submission, SubmissionStatus, Task, TaskConfig,
+        TaskKind, TokenPool, Versioned, Vesting, VestingSchedule, VoteRecord,
+    },
 };
 
 pub struct Processor;
@@ -29,15 +44,47 @@ impl Processor {
         let instruction = BountyBoardInstruction::unpack(instruction_data)
             .map_err(|_| ProgramError::InvalidInstructionData)?;
 
+        if let BountyBoardInstruction::InitializeProgramConfig {
+            admin,
+            fees,
+            features,
+            limits,
+        } = instruction
+        {
+            msg!("Instruction: Initialize Program Config");
+            return Self::process_initialize_program_config(
+                program_id, accounts, admin, fees, features, limits,
+            );
+        }
+
+        // Every other instruction follows the program config
+        let (config_info, accounts) = accounts
+            .split_first()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let program_config = Self::load_program_config(program_id, config_info)?;
+        Self::verify_enabled(&program_config, &instruction)?;
+
         match instruction {
             BountyBoardInstruction::InitializeBoard {
                 name,
                 description,
                 img,
+                metadata,
                 config,
+                reward_asset,
             } => {
                 msg!("Instruction: Initialize Board");
-                Self::process_initialize_board(program_id, accounts, name, description, img, config)
+                Self::process_initialize_board(
+                    program_id,
+                    accounts,
+                    &program_config,
+                    name,
+                    description,
+                    img,
+                    metadata,
+                    config,
+                    reward_asset,
+                )
             }
             BountyBoardInstruction::CreateTask {
                 name,
@@ -45,36 +92,171 @@ impl Processor {
                 deadline,
                 max_completions,
                 reward_amount,
+                metadata,
                 config,
-                allow_self_check,
+                eligibility,
+                approval_threshold,
+                submission_deposit,
+                deposit_kind,
+                reward_tiers,
+                kind,
+                prize_shares_bps,
+                reward_mint,
+                vesting,
+                milestone_shares_bps,
+                recurrence,
+                review_grace_period,
+                expiry_policy,
+                description_ref,
             } => {
                 msg!("Instruction: Create Task");
                 Self::process_create_task(
                     program_id,
                     accounts,
+                    &program_config,
                     name,
                     description,
                     deadline,
                     max_completions,
                     reward_amount,
+                    metadata,
                     config,
-                    allow_self_check,
+                    eligibility,
+                    approval_threshold,
+                    submission_deposit,
+                    deposit_kind,
+                    reward_tiers,
+                    kind,
+                    prize_shares_bps,
+                    reward_mint,
+                    vesting,
+                    milestone_shares_bps,
+                    recurrence,
+                    review_grace_period,
+                    expiry_policy,
+                    description_ref,
                 )
             }
+            BountyBoardInstruction::RollTask => {
+                msg!("Instruction: Roll Task");
+                Self::process_roll_task(program_id, accounts)
+            }
+            BountyBoardInstruction::ExpireTask => {
+                msg!("Instruction: Expire Task");
+                Self::process_expire_task(program_id, accounts)
+            }
+            BountyBoardInstruction::AddReviewer { reviewer } => {
+                msg!("Instruction: Add Reviewer");
+                Self::process_add_reviewer(program_id, accounts, &program_config, reviewer)
+            }
             BountyBoardInstruction::JoinBoard => {
                 msg!("Instruction: Join Board");
                 Self::process_join_board(program_id, accounts)
             }
-            BountyBoardInstruction::SubmitProof { proof } => {
+            BountyBoardInstruction::UpdateMember { role, reputation } => {
+                msg!("Instruction: Update Member");
+                Self::process_update_member(program_id, accounts, role, reputation)
+            }
+            BountyBoardInstruction::SetPayoutAddress { payout_address } => {
+                msg!("Instruction: Set Payout Address");
+                Self::process_set_payout_address(program_id, accounts, payout_address)
+            }
+            BountyBoardInstruction::SubmitProof {
+                proof,
+                contributors,
+                proof_ref,
+                encrypted_proof,
+            } => {
                 msg!("Instruction: Submit Proof");
-                Self::process_submit_proof(program_id, accounts, proof)
+                Self::process_submit_proof(
+                    program_id,
+                    accounts,
+                    proof,
+                    contributors,
+                    proof_ref,
+                    encrypted_proof,
+                    None,
+                )
+            }
+            BountyBoardInstruction::CommitProof {
+                commitment,
+                contributors,
+            } => {
+                msg!("Instruction: Commit Proof");
+                Self::process_submit_proof(
+                    program_id,
+                    accounts,
+                    String::new(),
+                    contributors,
+                    None,
+                    None,
+                    Some(commitment),
+                )
+            }
+            BountyBoardInstruction::RevealProof {
+                proof,
+                salt,
+                proof_ref,
+                encrypted_proof,
+            } => {
+                msg!("Instruction: Reveal Proof");
+                Self::process_reveal_proof(
+                    program_id,
+                    accounts,
+                    proof,
+                    salt,
+                    proof_ref,
+                    encrypted_proof,
+                )
+            }
+            BountyBoardInstruction::SubmitMilestone {
+                proof,
+                proof_ref,
+                encrypted_proof,
+            } => {
+                msg!("Instruction: Submit Milestone");
+                Self::process_submit_milestone(
+                    program_id,
+                    accounts,
+                    proof,
+                    proof_ref,
+                    encrypted_proof,
+                )
             }
             BountyBoardInstruction::ReviewSubmission {
                 status,
                 review_comment,
+                reward,
             } => {
                 msg!("Instruction: Review Submission");
-                Self::process_review_submission(program_id, accounts, status, review_comment)
+                Self::process_review_submission(
+                    program_id,
+                    accounts,
+                    status,
+                    review_comment,
+                    reward,
+                    None,
+                )
+            }
+            BountyBoardInstruction::CommitVote { commitment } => {
+                msg!("Instruction: Commit Vote");
+                Self::process_commit_vote(program_id, accounts, commitment)
+            }
+            BountyBoardInstruction::RevealVote {
+                status,
+                review_comment,
+                reward,
+                salt,
+            } => {
+                msg!("Instruction: Reveal Vote");
+                Self::process_review_submission(
+                    program_id,
+                    accounts,
+                    status,
+                    review_comment,
+                    reward,
+                    Some(salt),
+                )
             }
             BountyBoardInstruction::SelfCheckSubmission {
                 signature,
@@ -83,24 +265,124 @@ impl Processor {
                 msg!("Instruction: Self Check Submission");
                 Self::process_self_check_submission(program_id, accounts, signature, check_data)
             }
-            BountyBoardInstruction::PledgeTokens { amount } => {
+            BountyBoardInstruction::SubmitRanking => {
+                msg!("Instruction: Submit Ranking");
+                Self::process_submit_ranking(program_id, accounts)
+            }
+            BountyBoardInstruction::FinalizeContest => {
+                msg!("Instruction: Finalize Contest");
+                Self::process_finalize_contest(program_id, accounts)
+            }
+            BountyBoardInstruction::ClaimReward => {
+                msg!("Instruction: Claim Reward");
+                Self::process_claim_reward(program_id, accounts)
+            }
+            BountyBoardInstruction::ClaimVested => {
+                msg!("Instruction: Claim Vested");
+                Self::process_claim_vested(program_id, accounts)
+            }
+            BountyBoardInstruction::AddRewardMint => {
+                msg!("Instruction: Add Reward Mint");
+                Self::process_add_reward_mint(program_id, accounts, &program_config)
+            }
+            BountyBoardInstruction::PledgeTokens {
+                amount,
+                reward_mint,
+            } => {
                 msg!("Instruction: Pledge Tokens");
-                Self::process_pledge_tokens(program_id, accounts, amount)
+                Self::process_pledge_tokens(program_id, accounts, amount, reward_mint)
+            }
+            BountyBoardInstruction::SetArbiters {
+                arbiters,
+                dispute_bond,
+            } => {
+                msg!("Instruction: Set Arbiters");
+                Self::process_set_arbiters(program_id, accounts, arbiters, dispute_bond)
+            }
+            BountyBoardInstruction::SetSlashDestination { destination } => {
+                msg!("Instruction: Set Slash Destination");
+                Self::process_set_slash_destination(program_id, accounts, destination)
+            }
+            BountyBoardInstruction::UpdateBoardConfig { config } => {
+                msg!("Instruction: Update Board Config");
+                Self::process_update_board_config(program_id, accounts, config)
+            }
+            BountyBoardInstruction::OpenDispute => {
+                msg!("Instruction: Open Dispute");
+                Self::process_open_dispute(program_id, accounts)
+            }
+            BountyBoardInstruction::ResolveDispute { overturn, comment } => {
+                msg!("Instruction: Resolve Dispute");
+                Self::process_resolve_dispute(program_id, accounts, overturn, comment)
+            }
+            BountyBoardInstruction::DisputeVesting => {
+                msg!("Instruction: Dispute Vesting");
+                Self::process_dispute_vesting(program_id, accounts)
+            }
+            BountyBoardInstruction::ClawbackVesting { comment } => {
+                msg!("Instruction: Clawback Vesting");
+                Self::process_clawback_vesting(program_id, accounts, comment)
             }
             BountyBoardInstruction::CloseBoard => {
                 msg!("Instruction: Close Board");
                 Self::process_close_board(program_id, accounts)
             }
+            BountyBoardInstruction::CloseTask => {
+                msg!("Instruction: Close Task");
+                Self::process_close_task(program_id, accounts)
+            }
+            BountyBoardInstruction::CloseSubmission => {
+                msg!("Instruction: Close Submission");
+                Self::process_close_submission(program_id, accounts)
+            }
+            BountyBoardInstruction::MigrateAccount { kind } => {
+                msg!("Instruction: Migrate Account");
+                Self::process_migrate_account(program_id, accounts, kind)
+            }
+            // Handled before the program config is loaded
+            BountyBoardInstruction::InitializeProgramConfig { .. } => {
+                Err(ProgramError::InvalidInstructionData)
+            }
+            BountyBoardInstruction::UpdateProgramConfig {
+                fees,
+                features,
+                limits,
+            } => {
+                msg!("Instruction: Update Program Config");
+                let mut program_config = program_config;
+                Self::verify_admin(&program_config, accounts)?;
+                program_config.fees = fees;
+                program_config.features = features;
+                program_config.limits = limits;
+                Self::save_program_config(&program_config, config_info)
+            }
+            BountyBoardInstruction::SetProgramAdmin { admin } => {
+                msg!("Instruction: Set Program Admin");
+                let mut program_config = program_config;
+                Self::verify_admin(&program_config, accounts)?;
+                program_config.admin = admin;
+                Self::save_program_config(&program_config, config_info)
+            }
+            BountyBoardInstruction::SetPaused { paused } => {
+                msg!("Instruction: Set Paused");
+                let mut program_config = program_config;
+                Self::verify_admin(&program_config, accounts)?;
+                program_config.paused = paused;
+                Self::save_program_config(&program_config, config_info)
+            }
         }
     }
 
     fn process_initialize_board(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        program_config: &ProgramConfig,
         name: String,
         description: String,
         img: String,
-        config: String,
+        metadata: String,
+        config: BoardConfig,
+        reward_asset: RewardAsset,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let creator_info = next_account_info(account_info_iter)?;
@@ -114,7 +396,15 @@ impl Processor {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Verify rent exemption
+        // Verify field sizes
+        Board::validate_fields(&name, &description, &img, &metadata)?;
+
+        if config.version != BoardConfig::VERSION {
+            return Err(BountyBoardError::InvalidConfigVersion.into());
+        }
+
+        // Verify the board account is new and rent exempt
+        Self::verify_new_account::<Board>(program_id, board_info)?;
         let rent = &Rent::from_account_info(rent_info)?;
         if !rent.is_exempt(board_info.lamports(), board_info.data_len()) {
             return Err(BountyBoardError::NotRentExempt.into());
@@ -122,17 +412,113 @@ impl Processor {
 
         // Initialize board data
         let mut board = Board::default();
+        board.header = Board::header();
         board.is_initialized = true;
         board.creator = *creator_info.key;
         board.name = name;
         board.description = description;
         board.img = img;
-        board.reward_token = *reward_token_info.key;
+        board.reward_asset = reward_asset;
         board.created_at = Clock::get()?.unix_timestamp;
+        board.metadata = metadata;
         board.config = config;
+        board.authority_bump = Self::find_authority(program_id, board_info.key).1;
+
+        match reward_asset {
+            RewardAsset::Token => {
+                // Either the SPL Token or the Token-2022 program may own the mint
+                check_spl_token_program_account(reward_token_info.owner)?;
+                board.reward_token = *reward_token_info.key;
+                board.token_program = *reward_token_info.owner;
+
+                // Create the vault authority's token account holding the pledges
+                let vault_info = next_account_info(account_info_iter)?;
+                let authority_info = next_account_info(account_info_iter)?;
+                let token_program_info = next_account_info(account_info_iter)?;
+                let associated_token_program_info = next_account_info(account_info_iter)?;
+                Self::verify_authority(program_id, &board, board_info, authority_info)?;
+                if *token_program_info.key != board.token_program
+                    || *vault_info.key
+                        != get_associated_token_address_with_program_id(
+                            authority_info.key,
+                            reward_token_info.key,
+                            token_program_info.key,
+                        )
+                {
+                    return Err(BountyBoardError::InvalidTokenAccount.into());
+                }
+                if vault_info.data_is_empty() {
+                    invoke(
+                        &associated_token_instruction::create_associated_token_account(
+                            creator_info.key,
+                            authority_info.key,
+                            reward_token_info.key,
+                            token_program_info.key,
+                        ),
+                        &[
+                            creator_info.clone(),
+                            vault_info.clone(),
+                            authority_info.clone(),
+                            reward_token_info.clone(),
+                            system_program_info.clone(),
+                            token_program_info.clone(),
+                            associated_token_program_info.clone(),
+                        ],
+                    )?;
+                }
+                board.vault = *vault_info.key;
+            }
+            RewardAsset::Sol => {
+                // Create the lamport vault holding the board's pledges
+                let vault_info = next_account_info(account_info_iter)?;
+                let (vault_pda, bump_seed) =
+                    Pubkey::find_program_address(&[b"vault", board_info.key.as_ref()], program_id);
+                if vault_pda != *vault_info.key {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                invoke_signed(
+                    &system_instruction::create_account(
+                        creator_info.key,
+                        &vault_pda,
+                        rent.minimum_balance(0),
+                        0,
+                        program_id,
+                    ),
+                    &[
+                        creator_info.clone(),
+                        vault_info.clone(),
+                        system_program_info.clone(),
+                    ],
+                    &[&[b"vault", board_info.key.as_ref(), &[bump_seed]]],
+                )?;
+                board.vault = vault_pda;
+            }
+        }
+
+        // Charge the protocol fee
+        let board_fee = program_config.fees.board_fee;
+        if board_fee > 0 {
+            let recipient_info = next_account_info(account_info_iter)?;
+            Self::charge_fee(
+                program_config,
+                board_fee,
+                creator_info,
+                recipient_info,
+                system_program_info,
+            )?;
+        }
 
         // Save board data
-        board.serialize(&mut *board_info.data.borrow_mut())?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::BoardCreated {
+            board: *board_info.key,
+            creator: board.creator,
+            reward_asset: board.reward_asset,
+            reward_token: board.reward_token,
+        }
+        .emit()?;
 
         Ok(())
     }
@@ -140,13 +526,28 @@ impl Processor {
     fn process_create_task(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
+        program_config: &ProgramConfig,
         name: String,
         description: String,
         deadline: i64,
         max_completions: u64,
         reward_amount: u64,
-        config: String,
-        allow_self_check: bool,
+        metadata: String,
+        config: TaskConfig,
+        eligibility: Eligibility,
+        approval_threshold: u8,
+        submission_deposit: u64,
+        deposit_kind: DepositKind,
+        reward_tiers: Vec<u64>,
+        kind: TaskKind,
+        prize_shares_bps: Vec<u16>,
+        reward_mint: Pubkey,
+        vesting: Option<VestingSchedule>,
+        milestone_shares_bps: Vec<u16>,
+        recurrence: Option<Recurrence>,
+        review_grace_period: i64,
+        expiry_policy: ExpiryPolicy,
+        description_ref: Option<ContentRef>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let creator_info = next_account_info(account_info_iter)?;
@@ -158,8 +559,15 @@ impl Processor {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        // Verify field sizes
+        Task::validate_fields(&name, &description, &metadata)?;
+        if let Some(content) = &description_ref {
+            content.validate()?;
+        }
+        Self::verify_new_account::<Task>(program_id, task_info)?;
+
         // Verify board state
-        let board = Board::try_from_slice(&board_info.data.borrow())?;
+        let mut board = Board::load(&board_info.data.borrow())?;
         if !board.is_initialized {
             return Err(BountyBoardError::BoardNotInitialized.into());
         }
@@ -169,22 +577,358 @@ impl Processor {
         if board.creator != *creator_info.key {
             return Err(ProgramError::InvalidAccountData);
         }
+        if config.version != TaskConfig::VERSION {
+            return Err(BountyBoardError::InvalidConfigVersion.into());
+        }
+        if approval_threshold == 0 {
+            return Err(BountyBoardError::InvalidApprovalThreshold.into());
+        }
+        if reward_mint != Pubkey::default() && board.pool(&reward_mint).is_none() {
+            return Err(BountyBoardError::UnknownRewardMint.into());
+        }
+        if board.pays_lamports(&reward_mint) && deposit_kind == DepositKind::RewardToken {
+            return Err(BountyBoardError::InvalidDepositAccount.into());
+        }
+        // Only SOL boards can hold slashed lamport deposits themselves
+        if submission_deposit > 0
+            && deposit_kind == DepositKind::Lamports
+            && board.reward_asset != RewardAsset::Sol
+            && board.slash_destination == Pubkey::default()
+        {
+            return Err(BountyBoardError::SlashDestinationRequired.into());
+        }
+        if let Some(schedule) = vesting {
+            // Only reviewed standard tasks can hold back a reward
+            if !schedule.is_valid()
+                || kind != TaskKind::Standard
+                || config.self_check_policy == SelfCheckPolicy::Enabled
+            {
+                return Err(BountyBoardError::InvalidVestingSchedule.into());
+            }
+        }
+        if reward_tiers.iter().any(|tier| *tier > reward_amount) {
+            return Err(BountyBoardError::RewardExceedsCap.into());
+        }
+        let max_reward = program_config.limits.max_reward_amount;
+        if max_reward > 0 && reward_amount > max_reward {
+            return Err(BountyBoardError::RewardExceedsCap.into());
+        }
+        if kind == TaskKind::Contest {
+            // Contest submissions are never reviewed, so deposits could not be settled,
+            // and reviewers need a grace period to rank them before anyone can expire it
+            if deadline <= 0 || submission_deposit > 0 || review_grace_period <= 0 {
+                return Err(BountyBoardError::InvalidTaskKind.into());
+            }
+            let total_bps: u32 = prize_shares_bps.iter().map(|bps| *bps as u32).sum();
+            if prize_shares_bps.is_empty() || total_bps != Contributor::TOTAL_BPS as u32 {
+                return Err(BountyBoardError::InvalidPrizeShares.into());
+            }
+        }
+        if let Some(recurrence) = recurrence {
+            // Milestone and contest progress cannot be carried between cycles
+            if !recurrence.is_valid() || kind != TaskKind::Standard || deadline <= 0 {
+                return Err(BountyBoardError::InvalidRecurrence.into());
+            }
+        }
+        if review_grace_period < 0 {
+            return Err(BountyBoardError::InvalidInstruction.into());
+        }
+        // Reveals open at the deadline at the latest, so a reviewer who never
+        // commits cannot lock a submission
+        if config.blind_voting && deadline <= 0 {
+            return Err(BountyBoardError::DeadlineRequired.into());
+        }
+        if expiry_policy == ExpiryPolicy::ApprovePending
+            && (kind != TaskKind::Standard || vesting.is_some())
+        {
+            return Err(BountyBoardError::InvalidTaskKind.into());
+        }
+        if kind == TaskKind::Milestone {
+            let total_bps: u32 = milestone_shares_bps.iter().map(|bps| *bps as u32).sum();
+            if milestone_shares_bps.is_empty()
+                || milestone_shares_bps.len() > u8::MAX as usize
+                || total_bps != Contributor::TOTAL_BPS as u32
+            {
+                return Err(BountyBoardError::InvalidMilestoneShares.into());
+            }
+        }
+
+        // Charge the protocol fee
+        let task_fee = program_config.fees.task_fee;
+        if task_fee > 0 {
+            let recipient_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+            Self::charge_fee(
+                program_config,
+                task_fee,
+                creator_info,
+                recipient_info,
+                system_program_info,
+            )?;
+        }
 
         // Initialize task data
         let mut task = Task::default();
+        task.board = *board_info.key;
         task.name = name;
         task.creator = *creator_info.key;
         task.description = description;
+        task.description_ref = description_ref;
         task.deadline = deadline;
         task.max_completions = max_completions;
         task.reward_amount = reward_amount;
         task.created_at = Clock::get()?.unix_timestamp;
+        task.metadata = metadata;
         task.config = config;
-        task.allow_self_check = allow_self_check;
+        task.eligibility = eligibility;
+        task.approval_threshold = approval_threshold;
+        task.submission_deposit = submission_deposit;
+        task.deposit_kind = deposit_kind;
+        task.reward_tiers = reward_tiers;
+        task.kind = kind;
+        task.prize_shares_bps = prize_shares_bps;
+        task.reward_mint = reward_mint;
+        task.vesting = vesting;
+        task.milestone_shares_bps = milestone_shares_bps;
+        task.recurrence = recurrence;
+        task.review_grace_period = review_grace_period;
+        task.expiry_policy = expiry_policy;
         task.reviewers.push(*creator_info.key);
+        task.validate_lists()?;
+
+        // Reserve the rewards for every completion, or the contest's prize pool
+        let total_reward = task
+            .total_reward()
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        board.reserve(&task.reward_mint, total_reward)?;
+        task.reserved = total_reward;
 
         // Save task data
-        task.serialize(&mut *task_info.data.borrow_mut())?;
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::TaskCreated {
+            board: *board_info.key,
+            task: *task_info.key,
+            kind: task.kind,
+            reward_mint: task.reward_mint,
+            reward_amount: task.reward_amount,
+            max_completions: task.max_completions,
+            deadline: task.deadline,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_roll_task(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+
+        // Verify board and task state
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        if task.board != *board_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if task.cancelled {
+            return Err(BountyBoardError::TaskIsCancelled.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        let period = match task.recurrence {
+            Some(recurrence) => recurrence.period,
+            None => return Err(BountyBoardError::InvalidRecurrence.into()),
+        };
+        if !task.has_next_cycle() {
+            return Err(BountyBoardError::NoCyclesRemaining.into());
+        }
+        let review_ends = task.deadline.saturating_add(task.review_grace_period);
+        if review_ends >= Clock::get()?.unix_timestamp {
+            return Err(BountyBoardError::CycleNotEnded.into());
+        }
+
+        // Release the finished cycle's unfilled completions, then reserve the next cycle
+        board.release_reward(&task.reward_mint, task.reserved, 0)?;
+        let total_reward = task
+            .total_reward()
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        board.reserve(&task.reward_mint, total_reward)?;
+        task.reserved = total_reward;
+
+        task.cycle += 1;
+        task.deadline += period;
+        task.num_completions = 0;
+        task.completed = false;
+
+        // Save updates
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::TaskRolled {
+            task: *task_info.key,
+            cycle: task.cycle,
+            deadline: task.deadline,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_expire_task(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+
+        // Verify board and task state
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        if task.board != *board_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !task.expired && board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        let review_ends = task.deadline.saturating_add(task.review_grace_period);
+        let mut expiring =
+            !task.expired && task.deadline > 0 && review_ends < Clock::get()?.unix_timestamp;
+        // Recurring tasks expire after their last cycle, or once the board cannot fund the next
+        if expiring && task.has_next_cycle() {
+            let next_reward = task
+                .total_reward()
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            let fundable = board.available(&task.reward_mint).saturating_add(task.reserved);
+            expiring = fundable < next_reward;
+        }
+        // Otherwise only submissions left out of the task's expiry, or of a recurring
+        // task's earlier cycles, are rejected
+        let leftover = !expiring;
+        let mut num_resolved = 0;
+
+        // Resolve the pending submissions by the task's policy
+        while let Some(submission_info) = account_info_iter.next() {
+            let mut submission =
+                Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+            if submission.task != *task_info.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            let committed = submission.status == SubmissionStatus::Committed;
+            if submission.status != SubmissionStatus::Pending && !committed {
+                return Err(BountyBoardError::SubmissionNotPending.into());
+            }
+            let past_cycle = submission.cycle < task.cycle;
+            if leftover && !task.expired && !past_cycle {
+                return Err(BountyBoardError::TaskNotExpired.into());
+            }
+            num_resolved += 1;
+
+            // Unrevealed commitments and submissions of earlier cycles are always rejected
+            let approve = !leftover
+                && task.expiry_policy == ExpiryPolicy::ApprovePending
+                && !committed
+                && !past_cycle;
+            if approve && !task.completed {
+                let amount = task.reward_amount;
+                Self::approve_submission(&mut board, &mut task, &mut submission, amount)?;
+            } else {
+                submission.status = SubmissionStatus::Rejected;
+            }
+
+            // Refund the deposit; token deposits are credited as a payout
+            if submission.deposit > 0 {
+                let submitter = submission.submitter;
+                match task.deposit_kind {
+                    DepositKind::Lamports => {
+                        let submitter_info = next_account_info(account_info_iter)?;
+                        if *submitter_info.key != submitter {
+                            return Err(BountyBoardError::InvalidDepositAccount.into());
+                        }
+                        **submission_info.try_borrow_mut_lamports()? -= submission.deposit;
+                        **submitter_info.try_borrow_mut_lamports()? += submission.deposit;
+                    }
+                    DepositKind::RewardToken => submission.credit(submitter, submission.deposit),
+                }
+                submission.deposit = 0;
+            }
+
+            submission.save(submission_info)?;
+        }
+        if leftover {
+            if !task.expired && num_resolved == 0 {
+                return Err(BountyBoardError::TaskNotExpired.into());
+            }
+            return Ok(());
+        }
+
+        // Return what is still reserved for the task to the board
+        let released = task.reserved;
+        board.release_reward(&task.reward_mint, released, 0)?;
+        task.reserved = 0;
+        task.expired = true;
+
+        // Save updates
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::TaskExpired {
+            task: *task_info.key,
+            released,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_add_reviewer(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        program_config: &ProgramConfig,
+        reviewer: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify task ownership
+        let board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        if task.creator != *creator_info.key || task.board != *board_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if task.is_reviewer(&reviewer) {
+            return Err(BountyBoardError::ReviewerAlreadyExists.into());
+        }
+        if task.reviewers.len() >= Task::MAX_REVIEWERS {
+            return Err(BountyBoardError::TooManyReviewers.into());
+        }
+        let max_reviewers = board.config.max_reviewers_per_task as usize;
+        if max_reviewers > 0 && task.reviewers.len() >= max_reviewers {
+            return Err(BountyBoardError::TooManyReviewers.into());
+        }
+        let program_max = program_config.limits.max_reviewers_per_task as usize;
+        if program_max > 0 && task.reviewers.len() >= program_max {
+            return Err(BountyBoardError::TooManyReviewers.into());
+        }
+
+        task.reviewers.push(reviewer);
+        task.save(task_info)?;
+
+        BountyBoardEvent::ReviewerAdded {
+            task: *task_info.key,
+            reviewer,
+        }
+        .emit()?;
 
         Ok(())
     }
@@ -202,7 +946,7 @@ impl Processor {
         }
 
         // Verify board state
-        let mut board = Board::try_from_slice(&board_info.data.borrow())?;
+        let board = Board::load(&board_info.data.borrow())?;
         if !board.is_initialized {
             return Err(BountyBoardError::BoardNotInitialized.into());
         }
@@ -210,7 +954,7 @@ impl Processor {
             return Err(BountyBoardError::BoardIsClosed.into());
         }
 
-        // Create member PDA account
+        // Verify member PDA account
         let (member_pda, bump_seed) = Pubkey::find_program_address(
             &[
                 b"member",
@@ -224,9 +968,34 @@ impl Processor {
             return Err(ProgramError::InvalidAccountData);
         }
 
+        // Convert a baseline member account, a single byte flagging
+        // membership. Such members joined before any task could require
+        // joining first, so they keep the earliest join time.
+        if member_info.data_len() == 1 && member_info.owner == program_id {
+            let mut member = Member::default();
+            member.is_initialized = true;
+            member.board = *board_info.key;
+            member.user = *user_info.key;
+            member.joined_at = 0;
+            Self::rewrite(&member, member_info, user_info, system_program_info)?;
+            return Ok(());
+        }
+
+        // Verify the board's join policy
+        match board.config.join_policy {
+            JoinPolicy::Open => {}
+            JoinPolicy::CreatorApproval => {
+                let creator_info = next_account_info(account_info_iter)?;
+                if !creator_info.is_signer || *creator_info.key != board.creator {
+                    return Err(BountyBoardError::JoinNotAllowed.into());
+                }
+            }
+            JoinPolicy::Closed => return Err(BountyBoardError::JoinNotAllowed.into()),
+        }
+
         // Create member account if it doesn't exist
-        if member_info.data_borrow().is_empty() {
-            let space = 1; // Just a flag to indicate membership
+        if member_info.data_is_empty() {
+            let space = Member::LEN;
             let rent = Rent::get()?;
             let lamports = rent.minimum_balance(space);
 
@@ -250,353 +1019,3044 @@ impl Processor {
                     &[bump_seed],
                 ]],
             )?;
+
+            let mut member = Member::default();
+            member.is_initialized = true;
+            member.board = *board_info.key;
+            member.user = *user_info.key;
+            member.joined_at = Clock::get()?.unix_timestamp;
+            member.save(member_info)?;
+
+            BountyBoardEvent::MemberJoined {
+                board: *board_info.key,
+                user: *user_info.key,
+            }
+            .emit()?;
         }
 
         Ok(())
     }
 
-    fn process_submit_proof(
+    fn process_update_member(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        proof: String,
+        role: MemberRole,
+        reputation: u64,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
-        let submitter_info = next_account_info(account_info_iter)?;
+        let creator_info = next_account_info(account_info_iter)?;
         let board_info = next_account_info(account_info_iter)?;
-        let task_info = next_account_info(account_info_iter)?;
-        let submission_info = next_account_info(account_info_iter)?;
         let member_info = next_account_info(account_info_iter)?;
 
-        // Verify submitter signature
-        if !submitter_info.is_signer {
+        // Verify creator signature
+        if !creator_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Verify board state
-        let board = Board::try_from_slice(&board_info.data.borrow())?;
-        if !board.is_initialized {
-            return Err(BountyBoardError::BoardNotInitialized.into());
-        }
-        if board.closed {
-            return Err(BountyBoardError::BoardIsClosed.into());
-        }
-
-        // Verify membership
-        let (member_pda, _) = Pubkey::find_program_address(
-            &[
-                b"member",
-                board_info.key.as_ref(),
-                submitter_info.key.as_ref(),
-            ],
-            program_id,
-        );
-        if member_pda != *member_info.key || member_info.data_borrow().is_empty() {
-            return Err(BountyBoardError::NotABoardMember.into());
+        // Verify board ownership
+        let board = Board::load(&board_info.data.borrow())?;
+        if board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
         }
 
-        // Verify task state
-        let task = Task::try_from_slice(&task_info.data.borrow())?;
-        if task.completed {
-            return Err(BountyBoardError::TaskAlreadyCompleted.into());
-        }
-        if task.cancelled {
-            return Err(BountyBoardError::TaskIsCancelled.into());
+        // Verify member account
+        if member_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
         }
-        if task.deadline > 0 && task.deadline < Clock::get()?.unix_timestamp {
-            return Err(BountyBoardError::TaskDeadlinePassed.into());
+        let mut member = Member::load(&member_info.data.borrow())?;
+        if !member.is_initialized || member.board != *board_info.key {
+            return Err(BountyBoardError::NotABoardMember.into());
         }
 
-        // Create submission
-        let mut submission = Submission::default();
-        submission.submitter = *submitter_info.key;
-        submission.proof = proof;
-        submission.status = SubmissionStatus::Pending;
-        submission.submitted_at = Clock::get()?.unix_timestamp;
+        member.role = role;
+        member.reputation = reputation;
+        member.save(member_info)?;
 
-        // Save submission
-        submission.serialize(&mut *submission_info.data.borrow_mut())?;
+        BountyBoardEvent::MemberUpdated {
+            board: *board_info.key,
+            user: member.user,
+            role,
+            reputation,
+        }
+        .emit()?;
 
         Ok(())
     }
 
-    fn process_review_submission(
+    fn process_set_payout_address(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        status: i8,
-        review_comment: String,
+        payout_address: Pubkey,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
-        let reviewer_info = next_account_info(account_info_iter)?;
+        let user_info = next_account_info(account_info_iter)?;
         let board_info = next_account_info(account_info_iter)?;
-        let task_info = next_account_info(account_info_iter)?;
-        let submission_info = next_account_info(account_info_iter)?;
-        let submitter_token_info = next_account_info(account_info_iter)?;
-        let board_token_info = next_account_info(account_info_iter)?;
-        let token_program_info = next_account_info(account_info_iter)?;
+        let member_info = next_account_info(account_info_iter)?;
 
-        // Verify reviewer signature
-        if !reviewer_info.is_signer {
+        // Verify user signature
+        if !user_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Load accounts
-        let board = Board::try_from_slice(&board_info.data.borrow())?;
-        let mut task = Task::try_from_slice(&task_info.data.borrow())?;
-        let mut submission = Submission::try_from_slice(&submission_info.data.borrow())?;
+        let mut member = Self::load_member(program_id, board_info, user_info.key, member_info)?;
+        member.payout_address = payout_address;
+        member.save(member_info)?;
 
-        // Verify reviewer authority
-        let mut is_reviewer = false;
-        for reviewer in &task.reviewers {
-            if reviewer == reviewer_info.key {
-                is_reviewer = true;
-                break;
-            }
-        }
-        if !is_reviewer {
-            return Err(BountyBoardError::NotATaskReviewer.into());
-        }
-
-        // Update submission status
-        submission.status = match status {
-            1 => SubmissionStatus::Approved,
-            -1 => SubmissionStatus::Rejected,
-            _ => SubmissionStatus::Pending,
-        };
-        submission.review_comment = review_comment;
-
-        // Process reward if approved
-        if let SubmissionStatus::Approved = submission.status {
-            // Transfer reward tokens
-            let transfer_instruction = token_instruction::transfer(
-                token_program_info.key,
-                board_token_info.key,
-                submitter_token_info.key,
-                board_info.key,
-                &[],
-                task.reward_amount,
-            )?;
+        Ok(())
+    }
 
-            invoke_signed(
-                &transfer_instruction,
-                &[
-                    board_token_info.clone(),
-                    submitter_token_info.clone(),
-                    board_info.clone(),
-                    token_program_info.clone(),
-                ],
-                &[&[b"board", board_info.key.as_ref(), &[]]],
-            )?;
+    /// Verifies the member PDA of `user` on `board_info` and loads it
+    fn load_member(
+        program_id: &Pubkey,
+        board_info: &AccountInfo,
+        user: &Pubkey,
+        member_info: &AccountInfo,
+    ) -> Result<Member, ProgramError> {
+        let (member_pda, _) = Pubkey::find_program_address(
+            &[b"member", board_info.key.as_ref(), user.as_ref()],
+            program_id,
+        );
+        if member_pda != *member_info.key || member_info.data_is_empty() {
+            return Err(BountyBoardError::NotABoardMember.into());
+        }
 
-            // Update task completion status
-            task.num_completions += 1;
-            if task.num_completions >= task.max_completions {
-                task.completed = true;
-            }
+        let member = Member::load(&member_info.data.borrow())?;
+        if !member.is_initialized {
+            return Err(BountyBoardError::NotABoardMember.into());
         }
 
-        // Save updates
-        submission.serialize(&mut *submission_info.data.borrow_mut())?;
-        task.serialize(&mut *task_info.data.borrow_mut())?;
+        Ok(member)
+    }
 
+    /// Checks that a payout token account belongs to the token program of
+    /// `reward_mint`, holds that mint and is owned by one of `owners`
+    fn verify_token_account(
+        board: &Board,
+        reward_mint: &Pubkey,
+        token_info: &AccountInfo,
+        owners: &[Pubkey],
+    ) -> ProgramResult {
+        let (mint, token_program) = board.token_for(reward_mint)?;
+        if *token_info.owner != token_program {
+            return Err(BountyBoardError::InvalidTokenAccount.into());
+        }
+        let data = token_info.data.borrow();
+        let token_account = StateWithExtensions::<TokenAccount>::unpack(&data)?;
+        if token_account.base.mint != mint
+            || !owners.contains(&token_account.base.owner)
+        {
+            return Err(BountyBoardError::InvalidTokenAccount.into());
+        }
         Ok(())
     }
 
-    fn process_self_check_submission(
-        program_id: &Pubkey,
-        accounts: &[AccountInfo],
-        signature: Vec<u8>,
-        check_data: String,
-    ) -> ProgramResult {
-        let account_info_iter = &mut accounts.iter();
-        let submitter_info = next_account_info(account_info_iter)?;
-        let board_info = next_account_info(account_info_iter)?;
-        let task_info = next_account_info(account_info_iter)?;
-        let submission_info = next_account_info(account_info_iter)?;
-        let submitter_token_info = next_account_info(account_info_iter)?;
-        let board_token_info = next_account_info(account_info_iter)?;
-        let token_program_info = next_account_info(account_info_iter)?;
-        let member_info = next_account_info(account_info_iter)?;
+    /// Checks the token program and mint accounts match `reward_mint`
+    /// and returns the mint's decimals
+    fn reward_mint_decimals(
+        board: &Board,
+        reward_mint: &Pubkey,
+        token_program_info: &AccountInfo,
+        mint_info: &AccountInfo,
+    ) -> Result<u8, ProgramError> {
+        let (mint, token_program) = board.token_for(reward_mint)?;
+        if *token_program_info.key != token_program || *mint_info.owner != token_program {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if *mint_info.key != mint {
+            return Err(BountyBoardError::InvalidTokenAccount.into());
+        }
+        let data = mint_info.data.borrow();
+        let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+        Ok(mint.base.decimals)
+    }
 
-        // Verify submitter signature
-        if !submitter_info.is_signer {
-            return Err(ProgramError::MissingRequiredSignature);
+    /// Checks `board_token_info` is the board's vault for `reward_mint`:
+    /// its own token account, or the vault of the mint's pool
+    fn verify_board_token_account(
+        board: &Board,
+        reward_mint: &Pubkey,
+        board_token_info: &AccountInfo,
+    ) -> ProgramResult {
+        let vault = if *reward_mint == Pubkey::default() {
+            board.vault
+        } else {
+            board
+                .pool(reward_mint)
+                .ok_or(BountyBoardError::UnknownRewardMint)?
+                .vault
+        };
+        if vault != *board_token_info.key {
+            return Err(BountyBoardError::InvalidTokenAccount.into());
         }
+        Ok(())
+    }
 
-        // Verify board and task state
-        let board = Board::try_from_slice(&board_info.data.borrow())?;
-        let mut task = Task::try_from_slice(&task_info.data.borrow())?;
+    fn token_balance(token_info: &AccountInfo) -> Result<u64, ProgramError> {
+        let data = token_info.data.borrow();
+        let token_account = StateWithExtensions::<TokenAccount>::unpack(&data)?;
+        Ok(token_account.base.amount)
+    }
 
-        if !task.allow_self_check {
-            return Err(BountyBoardError::SelfCheckNotAllowed.into());
-        }
+    /// Transfers `amount` tokens into the board's token account and returns
+    /// how many arrived, which is less for mints charging a transfer fee
+    fn transfer_to_board<'a>(
+        board: &Board,
+        reward_mint: &Pubkey,
+        source_token_info: &AccountInfo<'a>,
+        board_token_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+        token_program_info: &AccountInfo<'a>,
+        mint_info: &AccountInfo<'a>,
+        amount: u64,
+    ) -> Result<u64, ProgramError> {
+        Self::verify_board_token_account(board, reward_mint, board_token_info)?;
+        let decimals =
+            Self::reward_mint_decimals(board, reward_mint, token_program_info, mint_info)?;
+        let transfer_instruction = token_instruction::transfer_checked(
+            token_program_info.key,
+            source_token_info.key,
+            mint_info.key,
+            board_token_info.key,
+            authority_info.key,
+            &[],
+            amount,
+            decimals,
+        )?;
 
-        // Verify membership
-        let (member_pda, _) = Pubkey::find_program_address(
+        let balance_before = Self::token_balance(board_token_info)?;
+        invoke(
+            &transfer_instruction,
             &[
-                b"member",
-                board_info.key.as_ref(),
-                submitter_info.key.as_ref(),
+                source_token_info.clone(),
+                mint_info.clone(),
+                board_token_info.clone(),
+                authority_info.clone(),
+                token_program_info.clone(),
             ],
+        )?;
+        let balance_after = Self::token_balance(board_token_info)?;
+
+        Ok(balance_after.saturating_sub(balance_before))
+    }
+
+    /// The vault authority owning the token accounts of `board_key`
+    fn find_authority(program_id: &Pubkey, board_key: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"authority", board_key.as_ref()], program_id)
+    }
+
+    /// Checks `authority_info` is the vault authority of the board
+    fn verify_authority(
+        program_id: &Pubkey,
+        board: &Board,
+        board_info: &AccountInfo,
+        authority_info: &AccountInfo,
+    ) -> ProgramResult {
+        let authority = Pubkey::create_program_address(
+            &[b"authority", board_info.key.as_ref(), &[board.authority_bump]],
             program_id,
-        );
-        if member_pda != *member_info.key || member_info.data_borrow().is_empty() {
-            return Err(BountyBoardError::NotABoardMember.into());
+        )?;
+        if authority != *authority_info.key {
+            return Err(ProgramError::InvalidSeeds);
         }
+        Ok(())
+    }
 
-        // Verify signature
-        // TODO: Implement proper signature verification using ed25519 program
-        let message = [
-            board_info.key.as_ref(),
-            task_info.key.as_ref(),
-            submitter_info.key.as_ref(),
-            check_data.as_bytes(),
-        ]
-        .concat();
-
-        // Create and approve submission
-        let mut submission = Submission::default();
-        submission.submitter = *submitter_info.key;
-        submission.status = SubmissionStatus::Approved;
-        submission.submitted_at = Clock::get()?.unix_timestamp;
-        submission.review_comment = check_data;
+    /// Transfers `amount` of `reward_mint` out of the board's token account,
+    /// signed by its vault authority, or moves lamports out of its vault for
+    /// lamport rewards
+    fn transfer_from_board<'a>(
+        board: &Board,
+        reward_mint: &Pubkey,
+        board_info: &AccountInfo<'a>,
+        board_token_info: &AccountInfo<'a>,
+        recipient_info: &AccountInfo<'a>,
+        token_program_info: Option<&AccountInfo<'a>>,
+        mint_info: Option<&AccountInfo<'a>>,
+        authority_info: Option<&AccountInfo<'a>>,
+        amount: u64,
+    ) -> ProgramResult {
+        if board.pays_lamports(reward_mint) {
+            if *board_token_info.key != board.vault {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            **board_token_info.try_borrow_mut_lamports()? -= amount;
+            **recipient_info.try_borrow_mut_lamports()? += amount;
+            return Ok(());
+        }
 
-        // Process reward
-        let transfer_instruction = token_instruction::transfer(
+        let token_program_info = token_program_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let mint_info = mint_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let authority_info = authority_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Self::verify_board_token_account(board, reward_mint, board_token_info)?;
+        let decimals =
+            Self::reward_mint_decimals(board, reward_mint, token_program_info, mint_info)?;
+        let transfer_instruction = token_instruction::transfer_checked(
             token_program_info.key,
             board_token_info.key,
-            submitter_token_info.key,
-            board_info.key,
+            mint_info.key,
+            recipient_info.key,
+            authority_info.key,
             &[],
-            task.reward_amount,
+            amount,
+            decimals,
         )?;
 
+        // The token program rejects any authority but the PDA of these seeds
         invoke_signed(
             &transfer_instruction,
             &[
                 board_token_info.clone(),
-                submitter_token_info.clone(),
-                board_info.clone(),
+                mint_info.clone(),
+                recipient_info.clone(),
+                authority_info.clone(),
                 token_program_info.clone(),
             ],
-            &[&[b"board", board_info.key.as_ref(), &[]]],
+            &[&[b"authority", board_info.key.as_ref(), &[board.authority_bump]]],
+        )
+    }
+
+    /// Checks a payout destination belongs to one of `owners`: a token
+    /// account of `reward_mint`, or the wallet itself for lamport rewards
+    fn verify_destination(
+        board: &Board,
+        reward_mint: &Pubkey,
+        destination_info: &AccountInfo,
+        owners: &[Pubkey],
+    ) -> ProgramResult {
+        if !board.pays_lamports(reward_mint) {
+            return Self::verify_token_account(board, reward_mint, destination_info, owners);
+        }
+        if !owners.contains(destination_info.key) {
+            return Err(BountyBoardError::InvalidTokenAccount.into());
+        }
+        Ok(())
+    }
+
+    /// Credits an approved submission `amount` for its payees to claim and
+    /// counts it towards the task's completions, releasing one reward
+    /// reservation on the board. Vesting tasks credit the reward as it
+    /// vests instead, see `start_vesting`.
+    fn approve_submission(
+        board: &mut Board,
+        task: &mut Task,
+        submission: &mut Submission,
+        amount: u64,
+    ) -> ProgramResult {
+        if task.vesting.is_none() {
+            submission.credit_reward(amount);
+        }
+        board.release_reward(&task.reward_mint, task.reward_amount, amount)?;
+        task.reserved = task
+            .reserved
+            .checked_sub(task.reward_amount)
+            .ok_or(BountyBoardError::InsufficientReservation)?;
+        task.record_completion();
+        submission.status = SubmissionStatus::Approved;
+        submission.approved_amount = amount;
+        Ok(())
+    }
+
+    /// Verifies a task can still approve a submission outside of review
+    fn verify_can_approve(task: &Task, submission: &Submission) -> ProgramResult {
+        if task.cancelled {
+            return Err(BountyBoardError::TaskIsCancelled.into());
+        }
+        if task.completed {
+            return Err(BountyBoardError::TaskAlreadyCompleted.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if submission.cycle != task.cycle {
+            return Err(BountyBoardError::SubmissionFromPastCycle.into());
+        }
+        if task.reserved == 0 {
+            return Err(BountyBoardError::InsufficientReservation.into());
+        }
+        Ok(())
+    }
+
+    /// Credits the current milestone of a milestone task's submission and
+    /// opens the next one for review, approving the submission after the
+    /// final milestone
+    fn approve_milestone(
+        board: &mut Board,
+        task: &mut Task,
+        submission: &mut Submission,
+    ) -> ProgramResult {
+        // Verify the milestone is still covered by the task's reservation,
+        // which other submissions may have drawn down
+        let amount = task.milestone_reward(submission.milestone as usize);
+        if amount > task.reserved {
+            return Err(BountyBoardError::InsufficientReservation.into());
+        }
+        submission.credit_reward(amount);
+        board.release_reward(&task.reward_mint, amount, amount)?;
+        task.reserved -= amount;
+        submission.approved_amount += amount;
+
+        if task.is_final_milestone(submission.milestone) {
+            task.record_completion();
+            submission.status = SubmissionStatus::Approved;
+        } else {
+            submission.status = SubmissionStatus::Pending;
+            submission.start_review_round();
+        }
+        submission.milestone += 1;
+
+        Ok(())
+    }
+
+    /// Creates the vesting account releasing an approved submission's
+    /// `amount` on the task's schedule, starting now
+    fn start_vesting<'a>(
+        program_id: &Pubkey,
+        schedule: VestingSchedule,
+        amount: u64,
+        payer_info: &AccountInfo<'a>,
+        submission_info: &AccountInfo<'a>,
+        vesting_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let (vesting_pda, bump_seed) =
+            Pubkey::find_program_address(&[b"vesting", submission_info.key.as_ref()], program_id);
+        if vesting_pda != *vesting_info.key || !vesting_info.data_is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                &vesting_pda,
+                rent.minimum_balance(Vesting::LEN),
+                Vesting::LEN as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                vesting_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[b"vesting", submission_info.key.as_ref(), &[bump_seed]]],
         )?;
 
-        // Update task completion status
-        task.num_completions += 1;
-        if task.num_completions >= task.max_completions {
-            task.completed = true;
+        let mut vesting = Vesting::default();
+        vesting.header = Vesting::header();
+        vesting.is_initialized = true;
+        vesting.submission = *submission_info.key;
+        vesting.total = amount;
+        vesting.start = Clock::get()?.unix_timestamp;
+        vesting.cliff = schedule.cliff;
+        vesting.duration = schedule.duration;
+        vesting.save(vesting_info)?;
+
+        Ok(())
+    }
+
+    /// Verifies the vesting PDA of `submission_info` and loads it
+    fn load_vesting(
+        program_id: &Pubkey,
+        submission_info: &AccountInfo,
+        vesting_info: &AccountInfo,
+    ) -> Result<Vesting, ProgramError> {
+        if vesting_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let vesting = Vesting::load(&vesting_info.data.borrow())?;
+        if !vesting.is_initialized || vesting.submission != *submission_info.key {
+            return Err(ProgramError::InvalidAccountData);
         }
 
-        // Save updates
-        submission.serialize(&mut *submission_info.data.borrow_mut())?;
-        task.serialize(&mut *task_info.data.borrow_mut())?;
+        Ok(vesting)
+    }
+
+    /// Transfers `payee`'s unclaimed payout to `destination_info`
+    /// and marks it claimed
+    fn claim_payout<'a>(
+        board: &Board,
+        task: &Task,
+        submission: &mut Submission,
+        payee: &Pubkey,
+        board_info: &AccountInfo<'a>,
+        board_token_info: &AccountInfo<'a>,
+        destination_info: &AccountInfo<'a>,
+        token_program_info: Option<&AccountInfo<'a>>,
+        mint_info: Option<&AccountInfo<'a>>,
+        authority_info: Option<&AccountInfo<'a>>,
+    ) -> ProgramResult {
+        let payout = submission
+            .payouts
+            .iter_mut()
+            .find(|payout| payout.recipient == *payee && !payout.claimed)
+            .ok_or(BountyBoardError::NothingToClaim)?;
+
+        Self::transfer_from_board(
+            board,
+            &task.reward_mint,
+            board_info,
+            board_token_info,
+            destination_info,
+            token_program_info,
+            mint_info,
+            authority_info,
+            payout.amount,
+        )?;
+        payout.claimed = true;
 
+        BountyBoardEvent::RewardPaid {
+            task: submission.task,
+            recipient: *payee,
+            destination: *destination_info.key,
+            amount: payout.amount,
+        }
+        .emit()
+    }
+
+    /// Refunds a submission's deposit to the submitter, or slashes it to
+    /// the board's slash destination. Token refunds are credited as a payout;
+    /// deposits slashed to the board are pledged to it.
+    fn settle_deposit<'a>(
+        board: &mut Board,
+        task: &Task,
+        submission: &mut Submission,
+        slash: bool,
+        board_info: &AccountInfo<'a>,
+        submission_info: &AccountInfo<'a>,
+        submitter_info: &AccountInfo<'a>,
+        destination_info: &AccountInfo<'a>,
+        board_token_info: &AccountInfo<'a>,
+        token_program_info: &AccountInfo<'a>,
+        mint_info: &AccountInfo<'a>,
+        authority_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let amount = submission.deposit;
+        let slash_to_board = board.slash_destination == Pubkey::default();
+
+        match task.deposit_kind {
+            DepositKind::Lamports => {
+                let recipient_info = if !slash {
+                    if *submitter_info.key != submission.submitter {
+                        return Err(BountyBoardError::InvalidDepositAccount.into());
+                    }
+                    submitter_info
+                } else if slash_to_board {
+                    // SOL boards keep slashed lamports as pledges in their vault
+                    if board.reward_asset != RewardAsset::Sol {
+                        return Err(BountyBoardError::SlashDestinationRequired.into());
+                    }
+                    if *destination_info.key != board.vault {
+                        return Err(BountyBoardError::InvalidDepositAccount.into());
+                    }
+                    board.pledge(&Pubkey::default(), amount)?;
+                    destination_info
+                } else {
+                    if *destination_info.key != board.slash_destination {
+                        return Err(BountyBoardError::InvalidDepositAccount.into());
+                    }
+                    destination_info
+                };
+                **submission_info.try_borrow_mut_lamports()? -= amount;
+                **recipient_info.try_borrow_mut_lamports()? += amount;
+            }
+            DepositKind::RewardToken => {
+                if !slash {
+                    let submitter = submission.submitter;
+                    submission.credit(submitter, amount);
+                } else if slash_to_board {
+                    board.pledge(&task.reward_mint, amount)?;
+                } else {
+                    Self::verify_token_account(
+                        board,
+                        &task.reward_mint,
+                        destination_info,
+                        &[board.slash_destination],
+                    )?;
+                    Self::transfer_from_board(
+                        board,
+                        &task.reward_mint,
+                        board_info,
+                        board_token_info,
+                        destination_info,
+                        Some(token_program_info),
+                        Some(mint_info),
+                        Some(authority_info),
+                        amount,
+                    )?;
+                }
+            }
+        }
+
+        submission.deposit = 0;
         Ok(())
     }
 
-    fn process_pledge_tokens(
+    fn process_submit_proof(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
-        amount: u64,
+        proof: String,
+        contributors: Vec<Contributor>,
+        proof_ref: Option<ContentRef>,
+        encrypted_proof: Option<EncryptedProof>,
+        commitment: Option<[u8; 32]>,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
-        let pledger_info = next_account_info(account_info_iter)?;
+        let submitter_info = next_account_info(account_info_iter)?;
         let board_info = next_account_info(account_info_iter)?;
-        let pledger_token_info = next_account_info(account_info_iter)?;
-        let board_token_info = next_account_info(account_info_iter)?;
-        let token_program_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let member_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
 
-        // Verify pledger signature
-        if !pledger_info.is_signer {
+        // Verify submitter signature
+        if !submitter_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
+        // Verify field sizes
+        Submission::validate_proof(&proof)?;
+        if let Some(content) = &proof_ref {
+            content.validate()?;
+        }
+
         // Verify board state
-        let mut board = Board::try_from_slice(&board_info.data.borrow())?;
+        let board = Board::load(&board_info.data.borrow())?;
+        if !board.is_initialized {
+            return Err(BountyBoardError::BoardNotInitialized.into());
+        }
         if board.closed {
             return Err(BountyBoardError::BoardIsClosed.into());
         }
 
-        // Verify pledger is board creator
-        if board.creator != *pledger_info.key {
+        // Verify membership
+        let member = Self::load_member(program_id, board_info, submitter_info.key, member_info)?;
+
+        // Verify task state
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        if task.board != *board_info.key {
             return Err(ProgramError::InvalidAccountData);
         }
+        if task.completed {
+            return Err(BountyBoardError::TaskAlreadyCompleted.into());
+        }
+        if task.cancelled {
+            return Err(BountyBoardError::TaskIsCancelled.into());
+        }
+        let max_submissions = task.config.max_submissions;
+        if max_submissions > 0 && task.num_submissions >= max_submissions {
+            return Err(BountyBoardError::SubmissionLimitReached.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if task.deadline > 0 && task.deadline < Clock::get()?.unix_timestamp {
+            return Err(BountyBoardError::TaskDeadlinePassed.into());
+        }
 
-        // Transfer tokens
-        let transfer_instruction = token_instruction::transfer(
-            token_program_info.key,
-            pledger_token_info.key,
-            board_token_info.key,
-            pledger_info.key,
-            &[],
-            amount,
-        )?;
+        // Verify eligibility
+        if !task.is_eligible(&member) {
+            return Err(BountyBoardError::NotEligible.into());
+        }
+        if !contributors.is_empty() {
+            Contributor::validate_shares(&contributors)?;
+        }
+        if let Some(encrypted) = &encrypted_proof {
+            encrypted.validate(&task.reviewers)?;
+        }
 
-        invoke(
-            &transfer_instruction,
-            &[
-                pledger_token_info.clone(),
-                board_token_info.clone(),
-                pledger_info.clone(),
-                token_program_info.clone(),
-            ],
+        // Create submission
+        Self::create_submission(
+            program_id,
+            task_info.key,
+            task.num_submissions,
+            submitter_info,
+            submission_info,
+            system_program_info,
         )?;
+        let mut submission = Submission::default();
+        submission.task = *task_info.key;
+        submission.submitter = *submitter_info.key;
+        submission.proof = proof;
+        submission.proof_ref = proof_ref;
+        submission.encrypted_proof = encrypted_proof;
+        submission.contributors = contributors;
+        submission.status = match commitment {
+            Some(_) => SubmissionStatus::Committed,
+            None => SubmissionStatus::Pending,
+        };
+        submission.commitment = commitment;
+        submission.submitted_at = Clock::get()?.unix_timestamp;
+        submission.cycle = task.cycle;
+
+        // Collect the submission deposit, recording what actually arrived
+        if task.submission_deposit > 0 {
+            submission.deposit = match task.deposit_kind {
+                DepositKind::Lamports => {
+                    invoke(
+                        &system_instruction::transfer(
+                            submitter_info.key,
+                            submission_info.key,
+                            task.submission_deposit,
+                        ),
+                        &[
+                            submitter_info.clone(),
+                            submission_info.clone(),
+                            system_program_info.clone(),
+                        ],
+                    )?;
+                    task.submission_deposit
+                }
+                DepositKind::RewardToken => {
+                    let submitter_token_info = next_account_info(account_info_iter)?;
+                    let board_token_info = next_account_info(account_info_iter)?;
+                    let token_program_info = next_account_info(account_info_iter)?;
+                    let mint_info = next_account_info(account_info_iter)?;
+                    Self::transfer_to_board(
+                        &board,
+                        &task.reward_mint,
+                        submitter_token_info,
+                        board_token_info,
+                        submitter_info,
+                        token_program_info,
+                        mint_info,
+                        task.submission_deposit,
+                    )?
+                }
+            };
+        }
 
-        // Update total pledged amount
-        board.total_pledged += amount;
-        board.serialize(&mut *board_info.data.borrow_mut())?;
+        // Save submission
+        task.num_submissions += 1;
+        task.open_submissions += 1;
+        submission.save(submission_info)?;
+        task.save(task_info)?;
+
+        BountyBoardEvent::ProofSubmitted {
+            task: *task_info.key,
+            submission: *submission_info.key,
+            submitter: *submitter_info.key,
+            deposit: submission.deposit,
+        }
+        .emit()?;
 
         Ok(())
     }
 
-    fn process_close_board(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    fn process_reveal_proof(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        proof: String,
+        salt: [u8; 32],
+        proof_ref: Option<ContentRef>,
+        encrypted_proof: Option<EncryptedProof>,
+    ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
-        let creator_info = next_account_info(account_info_iter)?;
-        let board_info = next_account_info(account_info_iter)?;
-        let board_token_info = next_account_info(account_info_iter)?;
-        let creator_token_info = next_account_info(account_info_iter)?;
-        let token_program_info = next_account_info(account_info_iter)?;
+        let submitter_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
 
-        // Verify creator signature
-        if !creator_info.is_signer {
+        // Verify submitter signature
+        if !submitter_info.is_signer {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Verify board state and ownership
-        let mut board = Board::try_from_slice(&board_info.data.borrow())?;
-        if board.creator != *creator_info.key {
+        // Verify field sizes
+        Submission::validate_proof(&proof)?;
+        if let Some(content) = &proof_ref {
+            content.validate()?;
+        }
+
+        // Verify task and submission state
+        let task = Task::load(&task_info.data.borrow())?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if submission.task != *task_info.key || submission.submitter != *submitter_info.key {
             return Err(ProgramError::InvalidAccountData);
         }
+        if task.cancelled {
+            return Err(BountyBoardError::TaskIsCancelled.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if submission.status != SubmissionStatus::Committed {
+            return Err(BountyBoardError::SubmissionNotCommitted.into());
+        }
+        if let Some(encrypted) = &encrypted_proof {
+            encrypted.validate(&task.reviewers)?;
+        }
 
-        // Return remaining tokens to creator
+        // Verify the proof matches the commitment
+        let commitment = Submission::commitment_for(
+            submitter_info.key,
+            &salt,
+            &proof,
+            &proof_ref,
+            &encrypted_proof,
+        )?;
+        if submission.commitment != Some(commitment) {
+            return Err(BountyBoardError::CommitmentMismatch.into());
+        }
+
+        submission.proof = proof;
+        submission.proof_ref = proof_ref;
+        submission.encrypted_proof = encrypted_proof;
+        submission.status = SubmissionStatus::Pending;
+        submission.revealed_at = Clock::get()?.unix_timestamp;
+        submission.save(submission_info)?;
+
+        BountyBoardEvent::ProofRevealed {
+            task: *task_info.key,
+            submission: *submission_info.key,
+            submitter: *submitter_info.key,
+            committed_at: submission.submitted_at,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_submit_milestone(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        proof: String,
+        proof_ref: Option<ContentRef>,
+        encrypted_proof: Option<EncryptedProof>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let submitter_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+
+        // Verify submitter signature
+        if !submitter_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify field sizes
+        Submission::validate_proof(&proof)?;
+        if let Some(content) = &proof_ref {
+            content.validate()?;
+        }
+
+        // Verify task and submission state
+        let task = Task::load(&task_info.data.borrow())?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if submission.task != *task_info.key || submission.submitter != *submitter_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if task.kind != TaskKind::Milestone {
+            return Err(BountyBoardError::InvalidTaskKind.into());
+        }
+        if submission.status != SubmissionStatus::Pending {
+            return Err(BountyBoardError::SubmissionNotPending.into());
+        }
+        if let Some(encrypted) = &encrypted_proof {
+            encrypted.validate(&task.reviewers)?;
+        }
+
+        submission.proof = proof;
+        submission.proof_ref = proof_ref;
+        submission.encrypted_proof = encrypted_proof;
+        submission.save(submission_info)?;
+
+        Ok(())
+    }
+
+    fn process_review_submission(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        status: i8,
+        review_comment: String,
+        reward: RewardChoice,
+        salt: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let reviewer_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let vote_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify reviewer signature
+        if !reviewer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify field sizes
+        Submission::validate_comment(&review_comment)?;
+
+        // Load accounts
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::verify_can_vote(&board, &task, &submission, reviewer_info.key)?;
+        // Milestone approvals always pay the milestone's share
+        if task.kind == TaskKind::Milestone && reward != RewardChoice::Full {
+            return Err(BountyBoardError::InvalidRewardTier.into());
+        }
+
+        // Record the vote, one per reviewer and review round of the submission.
+        // Blind votes were recorded when committed and must match it.
+        match salt {
+            None if task.config.blind_voting => {
+                return Err(BountyBoardError::BlindVotingRequired.into());
+            }
+            None => Self::create_vote_record(
+                program_id,
+                &submission,
+                submission_info,
+                reviewer_info,
+                vote_info,
+                system_program_info,
+                None,
+            )?,
+            Some(_) if !task.config.blind_voting => {
+                return Err(BountyBoardError::BlindVotingDisabled.into());
+            }
+            Some(salt) => {
+                let now = Clock::get()?.unix_timestamp;
+                let all_committed = submission.vote_commits >= submission.commit_reviewers;
+                if !all_committed && (task.deadline <= 0 || task.deadline >= now) {
+                    return Err(BountyBoardError::RevealNotOpen.into());
+                }
+
+                let (vote_pda, _) = Self::find_vote_record(
+                    program_id,
+                    submission_info.key,
+                    reviewer_info.key,
+                    submission.review_round,
+                );
+                if vote_pda != *vote_info.key || vote_info.owner != program_id {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let mut vote = VoteRecord::load(&vote_info.data.borrow())?;
+                if vote.revealed {
+                    return Err(BountyBoardError::AlreadyVoted.into());
+                }
+                let commitment =
+                    VoteRecord::commitment_for(reviewer_info.key, &salt, status, &reward)?;
+                if vote.commitment != Some(commitment) {
+                    return Err(BountyBoardError::CommitmentMismatch.into());
+                }
+                vote.revealed = true;
+                vote.voted_at = now;
+                vote.save(vote_info)?;
+            }
+        }
+
+        // Tally the vote
+        match status {
+            1 if task.kind == TaskKind::Milestone => submission.approvals += 1,
+            1 => {
+                let amount = task.reward_for(reward)?;
+                submission.approved_amount = if submission.approvals == 0 {
+                    amount
+                } else {
+                    submission.approved_amount.min(amount)
+                };
+                submission.approvals += 1;
+            }
+            -1 => submission.rejections += 1,
+            -2 => {
+                submission.rejections += 1;
+                submission.spam_votes += 1;
+            }
+            _ => return Err(BountyBoardError::InvalidInstruction.into()),
+        }
+
+        // Finalize once the outcome is decided
+        let approved = submission.approvals >= task.approval_threshold;
+        let rejected = !approved && !task.threshold_reachable(submission.rejections);
+        if approved || rejected {
+            submission.review_comment = review_comment;
+        }
+
+        if approved {
+            if task.completed {
+                return Err(BountyBoardError::TaskAlreadyCompleted.into());
+            }
+
+            if task.kind == TaskKind::Milestone {
+                Self::approve_milestone(&mut board, &mut task, &mut submission)?;
+            } else {
+                let amount = submission.approved_amount;
+                Self::approve_submission(&mut board, &mut task, &mut submission, amount)?;
+            }
+        } else if rejected && task.kind == TaskKind::Milestone && submission.milestone > 0 {
+            // Earlier milestones stay paid; the rejected one may be resubmitted
+            submission.start_review_round();
+        } else if rejected {
+            submission.status = SubmissionStatus::Rejected;
+        }
+
+        // Settle the deposit once the submission is finalized
+        let finalized = submission.status != SubmissionStatus::Pending;
+        if finalized && submission.deposit > 0 {
+            let submitter_info = next_account_info(account_info_iter)?;
+            let destination_info = next_account_info(account_info_iter)?;
+            let board_token_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+            let mint_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            let slash = rejected && submission.is_spam();
+            Self::settle_deposit(
+                &mut board,
+                &task,
+                &mut submission,
+                slash,
+                board_info,
+                submission_info,
+                submitter_info,
+                destination_info,
+                board_token_info,
+                token_program_info,
+                mint_info,
+                authority_info,
+            )?;
+        }
+
+        // Hold back the reward of a vesting task on its schedule
+        if let (true, Some(schedule)) = (approved, task.vesting) {
+            let vesting_info = next_account_info(account_info_iter)?;
+            Self::start_vesting(
+                program_id,
+                schedule,
+                submission.approved_amount,
+                reviewer_info,
+                submission_info,
+                vesting_info,
+                system_program_info,
+            )?;
+        }
+
+        // Save updates
+        submission.save(submission_info)?;
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::SubmissionReviewed {
+            task: *task_info.key,
+            submission: *submission_info.key,
+            reviewer: *reviewer_info.key,
+            vote: status,
+            status: submission.status.clone(),
+            approved_amount: submission.approved_amount,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_commit_vote(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        commitment: [u8; 32],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let reviewer_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let vote_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify reviewer signature
+        if !reviewer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load accounts
+        let board = Board::load(&board_info.data.borrow())?;
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::verify_can_vote(&board, &task, &submission, reviewer_info.key)?;
+        if !task.config.blind_voting {
+            return Err(BountyBoardError::BlindVotingDisabled.into());
+        }
+        // Nobody may commit after seeing a revealed vote of the round
+        if submission.approvals > 0 || submission.rejections > 0 {
+            return Err(BountyBoardError::CommitsClosed.into());
+        }
+
+        // Record the commitment in the reviewer's vote record
+        Self::create_vote_record(
+            program_id,
+            &submission,
+            submission_info,
+            reviewer_info,
+            vote_info,
+            system_program_info,
+            Some(commitment),
+        )?;
+        // Reviewers added later cannot hold back the round's reveals
+        if submission.vote_commits == 0 {
+            submission.commit_reviewers = task.reviewers.len() as u8;
+        }
+        submission.vote_commits = submission.vote_commits.saturating_add(1);
+        submission.save(submission_info)?;
+
+        BountyBoardEvent::VoteCommitted {
+            task: *task_info.key,
+            submission: *submission_info.key,
+            reviewer: *reviewer_info.key,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    /// Checks that `reviewer` may vote on the submission now
+    fn verify_can_vote(
+        board: &Board,
+        task: &Task,
+        submission: &Submission,
+        reviewer: &Pubkey,
+    ) -> ProgramResult {
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+
+        // Verify reviewer authority
+        if !task.is_reviewer(reviewer) {
+            return Err(BountyBoardError::NotATaskReviewer.into());
+        }
+        if task.kind == TaskKind::Contest {
+            return Err(BountyBoardError::InvalidTaskKind.into());
+        }
+        if submission.status != SubmissionStatus::Pending {
+            return Err(BountyBoardError::SubmissionNotPending.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if submission.cycle != task.cycle {
+            return Err(BountyBoardError::SubmissionFromPastCycle.into());
+        }
+        if task.config.review_policy == ReviewPolicy::ExcludeParticipants
+            && (submission.submitter == *reviewer
+                || submission.contributors.iter().any(|c| c.key == *reviewer))
+        {
+            return Err(BountyBoardError::ReviewerIsParticipant.into());
+        }
+        // Voting cannot start until enough reviewers have been added
+        if task.approval_threshold as usize > task.reviewers.len() {
+            return Err(BountyBoardError::InvalidApprovalThreshold.into());
+        }
+
+        Ok(())
+    }
+
+    /// Derives a reviewer's vote record for a review round of a submission
+    fn find_vote_record(
+        program_id: &Pubkey,
+        submission_key: &Pubkey,
+        reviewer_key: &Pubkey,
+        review_round: u8,
+    ) -> (Pubkey, u8) {
+        let round = [review_round];
+        let mut vote_seeds: Vec<&[u8]> =
+            vec![b"vote", submission_key.as_ref(), reviewer_key.as_ref()];
+        if review_round > 0 {
+            vote_seeds.push(&round);
+        }
+        Pubkey::find_program_address(&vote_seeds, program_id)
+    }
+
+    /// Creates the reviewer's vote record for the submission's review round,
+    /// failing if they already voted in it
+    fn create_vote_record<'a>(
+        program_id: &Pubkey,
+        submission: &Submission,
+        submission_info: &AccountInfo<'a>,
+        reviewer_info: &AccountInfo<'a>,
+        vote_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+        commitment: Option<[u8; 32]>,
+    ) -> ProgramResult {
+        let (vote_pda, bump_seed) = Self::find_vote_record(
+            program_id,
+            submission_info.key,
+            reviewer_info.key,
+            submission.review_round,
+        );
+        if vote_pda != *vote_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !vote_info.data_is_empty() {
+            return Err(BountyBoardError::AlreadyVoted.into());
+        }
+
+        let rent = Rent::get()?;
+        let round = [submission.review_round];
+        let bump = [bump_seed];
+        let mut vote_seeds: Vec<&[u8]> = vec![
+            b"vote",
+            submission_info.key.as_ref(),
+            reviewer_info.key.as_ref(),
+        ];
+        if submission.review_round > 0 {
+            vote_seeds.push(&round);
+        }
+        vote_seeds.push(&bump);
+        invoke_signed(
+            &system_instruction::create_account(
+                reviewer_info.key,
+                &vote_pda,
+                rent.minimum_balance(VoteRecord::LEN),
+                VoteRecord::LEN as u64,
+                program_id,
+            ),
+            &[
+                reviewer_info.clone(),
+                vote_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&vote_seeds],
+        )?;
+
+        let mut vote = VoteRecord::default();
+        vote.header = VoteRecord::header();
+        vote.is_initialized = true;
+        vote.submission = *submission_info.key;
+        vote.reviewer = *reviewer_info.key;
+        vote.voted_at = Clock::get()?.unix_timestamp;
+        vote.commitment = commitment;
+        vote.revealed = commitment.is_none();
+        vote.save(vote_info)
+    }
+
+    fn process_self_check_submission(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        _signature: Vec<u8>,
+        check_data: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let submitter_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let submitter_token_info = next_account_info(account_info_iter)?;
+        let board_token_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let member_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify submitter signature
+        if !submitter_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify field sizes
+        Submission::validate_comment(&check_data)?;
+
+        // Verify board and task state
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+
+        if task.board != *board_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if task.config.self_check_policy != SelfCheckPolicy::Enabled {
+            return Err(BountyBoardError::SelfCheckNotAllowed.into());
+        }
+        if task.kind != TaskKind::Standard {
+            return Err(BountyBoardError::InvalidTaskKind.into());
+        }
+        if task.completed {
+            return Err(BountyBoardError::TaskAlreadyCompleted.into());
+        }
+        if task.cancelled {
+            return Err(BountyBoardError::TaskIsCancelled.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if task.deadline > 0 && task.deadline < Clock::get()?.unix_timestamp {
+            return Err(BountyBoardError::TaskDeadlinePassed.into());
+        }
+
+        // Verify membership and eligibility
+        let member = Self::load_member(program_id, board_info, submitter_info.key, member_info)?;
+        if !task.is_eligible(&member) {
+            return Err(BountyBoardError::NotEligible.into());
+        }
+
+        // Verify the reward goes to the submitter in the task's reward mint
+        Self::verify_destination(
+            &board,
+            &task.reward_mint,
+            submitter_token_info,
+            &member.payout_owners(),
+        )?;
+
+        // Verify signature
+        // TODO: Implement proper signature verification using ed25519 program
+        let _message = [
+            board_info.key.as_ref(),
+            task_info.key.as_ref(),
+            submitter_info.key.as_ref(),
+            check_data.as_bytes(),
+        ]
+        .concat();
+
+        // Create and approve submission
+        Self::create_submission(
+            program_id,
+            task_info.key,
+            task.num_submissions,
+            submitter_info,
+            submission_info,
+            system_program_info,
+        )?;
+        let mut submission = Submission::default();
+        submission.task = *task_info.key;
+        submission.submitter = *submitter_info.key;
+        submission.submitted_at = Clock::get()?.unix_timestamp;
+        submission.review_comment = check_data;
+        submission.cycle = task.cycle;
+
+        // Process reward, paid out immediately to the submitter
+        let (mint_info, authority_info) = if board.pays_lamports(&task.reward_mint) {
+            (None, None)
+        } else {
+            let mint_info = next_account_info(account_info_iter)?;
+            (Some(mint_info), Some(next_account_info(account_info_iter)?))
+        };
+        let amount = task.reward_amount;
+        Self::approve_submission(&mut board, &mut task, &mut submission, amount)?;
+        Self::claim_payout(
+            &board,
+            &task,
+            &mut submission,
+            submitter_info.key,
+            board_info,
+            board_token_info,
+            submitter_token_info,
+            Some(token_program_info),
+            mint_info,
+            authority_info,
+        )?;
+
+        // Save updates
+        task.num_submissions += 1;
+        task.open_submissions += 1;
+        submission.save(submission_info)?;
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::SubmissionSelfChecked {
+            task: *task_info.key,
+            submission: *submission_info.key,
+            submitter: *submitter_info.key,
+            amount,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_submit_ranking(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let reviewer_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+
+        // Verify reviewer signature
+        if !reviewer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify task state
+        let mut task = Task::load(&task_info.data.borrow())?;
+        if !task.is_reviewer(reviewer_info.key) {
+            return Err(BountyBoardError::NotATaskReviewer.into());
+        }
+        if task.kind != TaskKind::Contest {
+            return Err(BountyBoardError::InvalidTaskKind.into());
+        }
+        if task.completed {
+            return Err(BountyBoardError::TaskAlreadyCompleted.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if task.deadline >= Clock::get()?.unix_timestamp {
+            return Err(BountyBoardError::ContestNotEnded.into());
+        }
+
+        // Verify the ranked submissions
+        let mut ranking = Vec::new();
+        for submission_info in account_info_iter {
+            let submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+            if submission.task != *task_info.key
+                || submission.status != SubmissionStatus::Pending
+                || ranking.contains(submission_info.key)
+            {
+                return Err(BountyBoardError::InvalidRanking.into());
+            }
+            ranking.push(*submission_info.key);
+        }
+        if ranking.len() > task.prize_shares_bps.len() {
+            return Err(BountyBoardError::InvalidRanking.into());
+        }
+
+        task.ranking = ranking;
+        task.save(task_info)?;
+
+        Ok(())
+    }
+
+    fn process_finalize_contest(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let reviewer_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+
+        // Verify reviewer signature
+        if !reviewer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board and task state
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        if task.board != *board_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !task.is_reviewer(reviewer_info.key) {
+            return Err(BountyBoardError::NotATaskReviewer.into());
+        }
+        if task.kind != TaskKind::Contest {
+            return Err(BountyBoardError::InvalidTaskKind.into());
+        }
+        if task.completed {
+            return Err(BountyBoardError::TaskAlreadyCompleted.into());
+        }
+        if task.expired {
+            return Err(BountyBoardError::TaskExpired.into());
+        }
+        if task.deadline >= Clock::get()?.unix_timestamp {
+            return Err(BountyBoardError::ContestNotEnded.into());
+        }
+
+        // Credit each ranked place its prize
+        let mut total_paid: u64 = 0;
+        for (place, ranked_key) in task.ranking.iter().enumerate() {
+            let submission_info = next_account_info(account_info_iter)?;
+            if submission_info.key != ranked_key {
+                return Err(BountyBoardError::InvalidRanking.into());
+            }
+            let mut submission =
+                Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+
+            let prize = task.prize_for(place);
+            submission.credit_reward(prize);
+            submission.status = SubmissionStatus::Approved;
+            submission.approved_amount = prize;
+            submission.save(submission_info)?;
+            total_paid += prize;
+        }
+
+        // Unawarded prizes and rounding dust stay with the board
+        board.release_reward(&task.reward_mint, task.reserved, total_paid)?;
+        task.reserved = 0;
+        task.num_completions = task.ranking.len() as u64;
+        task.completed = true;
+
+        // Save updates
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::ContestFinalized {
+            task: *task_info.key,
+            ranking: task.ranking.clone(),
+            total_paid,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_claim_reward(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payee_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+
+        // Verify payee signature
+        if !payee_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load accounts
+        let board = Board::load(&board_info.data.borrow())?;
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Self::claim_to_destination(
+            program_id,
+            account_info_iter,
+            payee_info,
+            board_info,
+            &board,
+            &task,
+            &mut submission,
+        )?;
+        submission.save(submission_info)?;
+
+        Ok(())
+    }
+
+    fn process_claim_vested(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payee_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let vesting_info = next_account_info(account_info_iter)?;
+
+        // Verify payee signature
+        if !payee_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load accounts
+        let board = Board::load(&board_info.data.borrow())?;
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let mut vesting = Self::load_vesting(program_id, submission_info, vesting_info)?;
+
+        // Release what has vested since the last claim to the payees
+        let releasable = vesting.releasable(Clock::get()?.unix_timestamp);
+        if releasable > 0 {
+            submission.credit_reward(releasable);
+            vesting.released += releasable;
+        }
+
+        Self::claim_to_destination(
+            program_id,
+            account_info_iter,
+            payee_info,
+            board_info,
+            &board,
+            &task,
+            &mut submission,
+        )?;
+
+        // Save updates
+        vesting.save(vesting_info)?;
+        submission.save(submission_info)?;
+
+        Ok(())
+    }
+
+    /// Transfers the payee's unclaimed payout of a submission to the
+    /// destination read from `account_info_iter`, creating the payee's
+    /// associated token account if needed
+    fn claim_to_destination<'a, 'b>(
+        program_id: &Pubkey,
+        account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+        payee_info: &'a AccountInfo<'b>,
+        board_info: &'a AccountInfo<'b>,
+        board: &Board,
+        task: &Task,
+        submission: &mut Submission,
+    ) -> ProgramResult {
+        let destination_info = next_account_info(account_info_iter)?;
+        let board_token_info = next_account_info(account_info_iter)?;
+
+        let token_accounts = if board.pays_lamports(&task.reward_mint) {
+            None
+        } else {
+            let mint_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+            let associated_token_program_info = next_account_info(account_info_iter)?;
+            let authority_info = next_account_info(account_info_iter)?;
+            Self::reward_mint_decimals(board, &task.reward_mint, token_program_info, mint_info)?;
+
+            // Create the payee's associated token account if missing
+            if destination_info.data_is_empty() {
+                if *destination_info.key
+                    != get_associated_token_address_with_program_id(
+                        payee_info.key,
+                        mint_info.key,
+                        token_program_info.key,
+                    )
+                {
+                    return Err(BountyBoardError::InvalidTokenAccount.into());
+                }
+                invoke(
+                    &associated_token_instruction::create_associated_token_account(
+                        payee_info.key,
+                        payee_info.key,
+                        mint_info.key,
+                        token_program_info.key,
+                    ),
+                    &[
+                        payee_info.clone(),
+                        destination_info.clone(),
+                        payee_info.clone(),
+                        mint_info.clone(),
+                        system_program_info.clone(),
+                        token_program_info.clone(),
+                        associated_token_program_info.clone(),
+                    ],
+                )?;
+            }
+            Some((token_program_info, mint_info, authority_info))
+        };
+
+        // Check the destination belongs to the payee or their payout address
+        let owners = match account_info_iter.next() {
+            Some(member_info) => {
+                Self::load_member(program_id, board_info, payee_info.key, member_info)?
+                    .payout_owners()
+            }
+            None => vec![*payee_info.key],
+        };
+        Self::verify_destination(board, &task.reward_mint, destination_info, &owners)?;
+
+        Self::claim_payout(
+            board,
+            task,
+            submission,
+            payee_info.key,
+            board_info,
+            board_token_info,
+            destination_info,
+            token_accounts.map(|(token_program_info, _, _)| token_program_info),
+            token_accounts.map(|(_, mint_info, _)| mint_info),
+            token_accounts.map(|(_, _, authority_info)| authority_info),
+        )
+    }
+
+    fn process_add_reward_mint(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        program_config: &ProgramConfig,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let vault_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+        let associated_token_program_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board state and ownership
+        let mut board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Verify the mint is new to the board
+        check_spl_token_program_account(token_program_info.key)?;
+        if mint_info.owner != token_program_info.key {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if *mint_info.key == Pubkey::default()
+            || (board.reward_asset == RewardAsset::Token && *mint_info.key == board.reward_token)
+            || board.pool(mint_info.key).is_some()
+        {
+            return Err(BountyBoardError::RewardMintAlreadyAdded.into());
+        }
+        let max_pools = program_config.limits.max_pools_per_board as usize;
+        if board.pools.len() >= Board::MAX_POOLS
+            || (max_pools > 0 && board.pools.len() >= max_pools)
+        {
+            return Err(BountyBoardError::TooManyRewardMints.into());
+        }
+
+        // Create the vault authority's associated token account as the pool's vault
+        Self::verify_authority(program_id, &board, board_info, authority_info)?;
+        if *vault_info.key
+            != get_associated_token_address_with_program_id(
+                authority_info.key,
+                mint_info.key,
+                token_program_info.key,
+            )
+        {
+            return Err(BountyBoardError::InvalidTokenAccount.into());
+        }
+        if vault_info.data_is_empty() {
+            invoke(
+                &associated_token_instruction::create_associated_token_account(
+                    creator_info.key,
+                    authority_info.key,
+                    mint_info.key,
+                    token_program_info.key,
+                ),
+                &[
+                    creator_info.clone(),
+                    vault_info.clone(),
+                    authority_info.clone(),
+                    mint_info.clone(),
+                    system_program_info.clone(),
+                    token_program_info.clone(),
+                    associated_token_program_info.clone(),
+                ],
+            )?;
+        }
+
+        let mut pool = TokenPool::default();
+        pool.mint = *mint_info.key;
+        pool.token_program = *token_program_info.key;
+        pool.vault = *vault_info.key;
+        board.pools.push(pool);
+        board.save(board_info)?;
+
+        BountyBoardEvent::RewardMintAdded {
+            board: *board_info.key,
+            mint: *mint_info.key,
+            vault: *vault_info.key,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_pledge_tokens(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+        reward_mint: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let pledger_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+
+        // Verify pledger signature
+        if !pledger_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board state
+        let mut board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+
+        // Verify pledger is board creator
+        if board.creator != *pledger_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Transfer lamports into the vault, or tokens
+        let received = if board.pays_lamports(&reward_mint) {
+            let vault_info = next_account_info(account_info_iter)?;
+            let system_program_info = next_account_info(account_info_iter)?;
+            if *vault_info.key != board.vault {
+                return Err(ProgramError::InvalidAccountData);
+            }
+            invoke(
+                &system_instruction::transfer(pledger_info.key, vault_info.key, amount),
+                &[
+                    pledger_info.clone(),
+                    vault_info.clone(),
+                    system_program_info.clone(),
+                ],
+            )?;
+            amount
+        } else {
+            let pledger_token_info = next_account_info(account_info_iter)?;
+            let board_token_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+            let mint_info = next_account_info(account_info_iter)?;
+            Self::transfer_to_board(
+                &board,
+                &reward_mint,
+                pledger_token_info,
+                board_token_info,
+                pledger_info,
+                token_program_info,
+                mint_info,
+                amount,
+            )?
+        };
+
+        // Update total pledged amount
+        board.pledge(&reward_mint, received)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::TokensPledged {
+            board: *board_info.key,
+            reward_mint,
+            amount: received,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_set_arbiters(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        arbiters: Vec<Pubkey>,
+        dispute_bond: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board state and ownership
+        let mut board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Token boards have no vault for forfeited bonds
+        if dispute_bond > 0
+            && board.reward_asset != RewardAsset::Sol
+            && board.slash_destination == Pubkey::default()
+        {
+            return Err(BountyBoardError::SlashDestinationRequired.into());
+        }
+
+        if arbiters.len() > Board::MAX_ARBITERS {
+            return Err(BountyBoardError::TooManyArbiters.into());
+        }
+
+        board.arbiters = arbiters;
+        board.dispute_bond = dispute_bond;
+        board.save(board_info)?;
+
+        Ok(())
+    }
+
+    fn process_set_slash_destination(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        destination: Pubkey,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board state and ownership
+        let mut board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Token boards have no vault for slashed lamport deposits
+        if destination == Pubkey::default() && board.reward_asset != RewardAsset::Sol {
+            return Err(BountyBoardError::SlashDestinationRequired.into());
+        }
+
+        board.slash_destination = destination;
+        board.save(board_info)?;
+
+        Ok(())
+    }
+
+    fn process_update_board_config(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        config: BoardConfig,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board state and ownership
+        let mut board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if config.version != BoardConfig::VERSION {
+            return Err(BountyBoardError::InvalidConfigVersion.into());
+        }
+
+        board.config = config;
+        board.save(board_info)?;
+
+        Ok(())
+    }
+
+    fn process_open_dispute(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let submitter_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let dispute_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify submitter signature
+        if !submitter_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board and submission state
+        let board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if board.arbiters.is_empty() {
+            return Err(BountyBoardError::NotAnArbiter.into());
+        }
+
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if submission.submitter != *submitter_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if submission.status != SubmissionStatus::Rejected {
+            return Err(BountyBoardError::SubmissionNotRejected.into());
+        }
+        Self::verify_can_approve(&task, &submission)?;
+
+        // Create dispute account holding the bond
+        let (dispute_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"dispute", submission_info.key.as_ref()],
+            program_id,
+        );
+        if dispute_pda != *dispute_info.key || !dispute_info.data_is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let rent = Rent::get()?;
+        let lamports = rent
+            .minimum_balance(Dispute::LEN)
+            .checked_add(board.dispute_bond)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        invoke_signed(
+            &system_instruction::create_account(
+                submitter_info.key,
+                &dispute_pda,
+                lamports,
+                Dispute::LEN as u64,
+                program_id,
+            ),
+            &[
+                submitter_info.clone(),
+                dispute_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[b"dispute", submission_info.key.as_ref(), &[bump_seed]]],
+        )?;
+
+        let mut dispute = Dispute::default();
+        dispute.header = Dispute::header();
+        dispute.is_initialized = true;
+        dispute.submission = *submission_info.key;
+        dispute.submitter = *submitter_info.key;
+        dispute.bond = board.dispute_bond;
+        dispute.opened_at = Clock::get()?.unix_timestamp;
+        dispute.save(dispute_info)?;
+
+        submission.status = SubmissionStatus::Disputed;
+        submission.save(submission_info)?;
+
+        BountyBoardEvent::DisputeOpened {
+            submission: *submission_info.key,
+            submitter: *submitter_info.key,
+            bond: dispute.bond,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_dispute_vesting(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let dispute_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board, task and submission state
+        let board = Board::load(&board_info.data.borrow())?;
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+        if board.arbiters.is_empty() {
+            return Err(BountyBoardError::NotAnArbiter.into());
+        }
+
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if task.creator != *creator_info.key && board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if task.vesting.is_none() {
+            return Err(BountyBoardError::InvalidVestingSchedule.into());
+        }
+        if submission.status != SubmissionStatus::Approved {
+            return Err(BountyBoardError::SubmissionNotApproved.into());
+        }
+
+        // Create the clawback dispute account
+        let (dispute_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"clawback", submission_info.key.as_ref()],
+            program_id,
+        );
+        if dispute_pda != *dispute_info.key || !dispute_info.data_is_empty() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                creator_info.key,
+                &dispute_pda,
+                rent.minimum_balance(Dispute::LEN),
+                Dispute::LEN as u64,
+                program_id,
+            ),
+            &[
+                creator_info.clone(),
+                dispute_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[b"clawback", submission_info.key.as_ref(), &[bump_seed]]],
+        )?;
+
+        let mut dispute = Dispute::default();
+        dispute.header = Dispute::header();
+        dispute.is_initialized = true;
+        dispute.submission = *submission_info.key;
+        dispute.submitter = submission.submitter;
+        dispute.opened_at = Clock::get()?.unix_timestamp;
+        dispute.clawback = true;
+        dispute.save(dispute_info)?;
+
+        BountyBoardEvent::DisputeOpened {
+            submission: *submission_info.key,
+            submitter: submission.submitter,
+            bond: 0,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_resolve_dispute(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        overturn: bool,
+        comment: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let arbiter_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let dispute_info = next_account_info(account_info_iter)?;
+
+        // Verify arbiter signature
+        if !arbiter_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify field sizes
+        Submission::validate_comment(&comment)?;
+
+        // Load accounts
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !board.is_arbiter(arbiter_info.key) {
+            return Err(BountyBoardError::NotAnArbiter.into());
+        }
+
+        // Verify dispute state
+        if dispute_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let mut dispute = Dispute::load(&dispute_info.data.borrow())?;
+        if dispute.submission != *submission_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if dispute.resolved {
+            return Err(BountyBoardError::DisputeAlreadyResolved.into());
+        }
+
+        // A clawback dispute holds no bond and leaves the approval in place
+        // until `ClawbackVesting` acts on the ruling
+        if dispute.clawback {
+            dispute.resolved = true;
+            dispute.overturned = overturn;
+            dispute.save(dispute_info)?;
+
+            BountyBoardEvent::DisputeResolved {
+                submission: *submission_info.key,
+                arbiter: *arbiter_info.key,
+                overturned: overturn,
+            }
+            .emit()?;
+
+            return Ok(());
+        }
+
+        let submitter_info = next_account_info(account_info_iter)?;
+        let forfeit_info = next_account_info(account_info_iter)?;
+        if dispute.submitter != *submitter_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if submission.status != SubmissionStatus::Disputed {
+            return Err(BountyBoardError::DisputeAlreadyResolved.into());
+        }
+
+        // Release the bond: refunded when overturned, forfeited otherwise to a
+        // SOL board's vault as a pledge, or to a token board's slash destination
+        let bond_recipient = if overturn {
+            submitter_info
+        } else if board.reward_asset == RewardAsset::Sol {
+            if *forfeit_info.key != board.vault {
+                return Err(BountyBoardError::InvalidDepositAccount.into());
+            }
+            board.pledge(&Pubkey::default(), dispute.bond)?;
+            forfeit_info
+        } else {
+            if board.slash_destination == Pubkey::default() {
+                return Err(BountyBoardError::SlashDestinationRequired.into());
+            }
+            if *forfeit_info.key != board.slash_destination {
+                return Err(BountyBoardError::InvalidDepositAccount.into());
+            }
+            forfeit_info
+        };
+        **dispute_info.try_borrow_mut_lamports()? -= dispute.bond;
+        **bond_recipient.try_borrow_mut_lamports()? += dispute.bond;
+
+        // A task that can no longer pay, e.g. completed by others meanwhile,
+        // only refunds the bond of an overturned rejection
+        if overturn && Self::verify_can_approve(&task, &submission).is_ok() {
+            if task.kind == TaskKind::Milestone {
+                Self::approve_milestone(&mut board, &mut task, &mut submission)?;
+            } else {
+                let amount = task.reward_amount;
+                Self::approve_submission(&mut board, &mut task, &mut submission, amount)?;
+            }
+            if let Some(schedule) = task.vesting {
+                let vesting_info = next_account_info(account_info_iter)?;
+                let system_program_info = next_account_info(account_info_iter)?;
+                Self::start_vesting(
+                    program_id,
+                    schedule,
+                    task.reward_amount,
+                    arbiter_info,
+                    submission_info,
+                    vesting_info,
+                    system_program_info,
+                )?;
+            }
+        } else {
+            submission.status = SubmissionStatus::Rejected;
+        }
+        submission.review_comment = comment;
+
+        dispute.resolved = true;
+        dispute.overturned = overturn;
+
+        // Save updates
+        dispute.save(dispute_info)?;
+        submission.save(submission_info)?;
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::DisputeResolved {
+            submission: *submission_info.key,
+            arbiter: *arbiter_info.key,
+            overturned: overturn,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_clawback_vesting(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        comment: String,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let admin_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let vesting_info = next_account_info(account_info_iter)?;
+        let dispute_info = next_account_info(account_info_iter)?;
+
+        // Verify admin signature
+        if !admin_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify field sizes
+        Submission::validate_comment(&comment)?;
+
+        // Load accounts
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let mut submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if board.creator != *admin_info.key && !board.is_arbiter(admin_info.key) {
+            return Err(BountyBoardError::NotAnArbiter.into());
+        }
+        let mut vesting = Self::load_vesting(program_id, submission_info, vesting_info)?;
+        if vesting.clawed_back {
+            return Err(BountyBoardError::VestingAlreadyClawedBack.into());
+        }
+
+        // Verify an arbiter overturned the approval
+        let (dispute_pda, _) = Pubkey::find_program_address(
+            &[b"clawback", submission_info.key.as_ref()],
+            program_id,
+        );
+        if dispute_pda != *dispute_info.key || dispute_info.owner != program_id {
+            return Err(BountyBoardError::ClawbackNotUpheld.into());
+        }
+        let dispute = Dispute::load(&dispute_info.data.borrow())?;
+        if !dispute.clawback || !dispute.resolved || !dispute.overturned {
+            return Err(BountyBoardError::ClawbackNotUpheld.into());
+        }
+
+        // Payees keep what has vested; the rest returns to the board
+        let vested = vesting.vested_at(Clock::get()?.unix_timestamp);
+        let releasable = vested.saturating_sub(vesting.released);
+        if releasable > 0 {
+            submission.credit_reward(releasable);
+        }
+        let returned = vesting.total - vested;
+        board.pledge(&task.reward_mint, returned)?;
+        vesting.released = vested;
+        vesting.total = vested;
+        vesting.clawed_back = true;
+        submission.review_comment = comment;
+
+        // Save updates
+        vesting.save(vesting_info)?;
+        submission.save(submission_info)?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::VestingClawedBack {
+            submission: *submission_info.key,
+            returned,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_close_board(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let board_token_info = next_account_info(account_info_iter)?;
+        let creator_token_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify board state and ownership
+        let mut board = Board::load(&board_info.data.borrow())?;
+        if board.creator != *creator_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if board.closed {
+            return Err(BountyBoardError::BoardIsClosed.into());
+        }
+
+        // Verify no task still holds a reservation
+        if board.reserved > 0 || board.pools.iter().any(|pool| pool.reserved > 0) {
+            return Err(BountyBoardError::BoardHasReservedFunds.into());
+        }
+
+        // Return remaining tokens to creator
+        let authority_info = next_account_info(account_info_iter)?;
+        let own_asset = Pubkey::default();
+        let mint_info = if board.pays_lamports(&own_asset) {
+            None
+        } else {
+            Some(next_account_info(account_info_iter)?)
+        };
         if board.total_pledged > 0 {
-            let transfer_instruction = token_instruction::transfer(
-                token_program_info.key,
-                board_token_info.key,
-                creator_token_info.key,
-                board_info.key,
-                &[],
+            Self::verify_destination(&board, &own_asset, creator_token_info, &[board.creator])?;
+            Self::transfer_from_board(
+                &board,
+                &own_asset,
+                board_info,
+                board_token_info,
+                creator_token_info,
+                Some(token_program_info),
+                mint_info,
+                Some(authority_info),
                 board.total_pledged,
             )?;
 
-            invoke_signed(
-                &transfer_instruction,
-                &[
-                    board_token_info.clone(),
-                    creator_token_info.clone(),
-                    board_info.clone(),
-                    token_program_info.clone(),
-                ],
-                &[&[b"board", board_info.key.as_ref(), &[]]],
+            board.total_pledged = 0;
+        }
+
+        // Return the remaining pledges of every pool
+        for index in 0..board.pools.len() {
+            let pool = board.pools[index].clone();
+            if pool.total_pledged == 0 {
+                continue;
+            }
+            let vault_info = next_account_info(account_info_iter)?;
+            let creator_token_info = next_account_info(account_info_iter)?;
+            let token_program_info = next_account_info(account_info_iter)?;
+            let mint_info = next_account_info(account_info_iter)?;
+            Self::verify_destination(&board, &pool.mint, creator_token_info, &[board.creator])?;
+            Self::transfer_from_board(
+                &board,
+                &pool.mint,
+                board_info,
+                vault_info,
+                creator_token_info,
+                Some(token_program_info),
+                Some(mint_info),
+                Some(authority_info),
+                pool.total_pledged,
             )?;
 
-            board.total_pledged = 0;
+            board.pools[index].total_pledged = 0;
         }
 
         // Close board
         board.closed = true;
-        board.serialize(&mut *board_info.data.borrow_mut())?;
+        board.save(board_info)?;
+
+        BountyBoardEvent::BoardClosed {
+            board: *board_info.key,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_close_task(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let creator_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+
+        // Verify creator signature
+        if !creator_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify task state and ownership
+        if task_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        if task.creator != *creator_info.key || task.board != *board_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !task.is_finished() {
+            return Err(BountyBoardError::TaskNotFinished.into());
+        }
+        if task.open_submissions > 0 {
+            return Err(BountyBoardError::TaskHasOpenSubmissions.into());
+        }
+        // Submissions of migrated tasks may still need the task account
+        if !task.submissions_tracked {
+            return Err(BountyBoardError::SubmissionsUntracked.into());
+        }
+
+        // Return what is still reserved for the task and archive it
+        board.release_reward(&task.reward_mint, task.reserved, 0)?;
+        board.archive.tasks_closed += 1;
+        board.archive.completions += task.num_completions;
+        board.save(board_info)?;
+
+        Self::close_account(task_info, creator_info)?;
+
+        BountyBoardEvent::TaskClosed {
+            board: *board_info.key,
+            task: *task_info.key,
+            completions: task.num_completions,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    fn process_close_submission(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let closer_info = next_account_info(account_info_iter)?;
+        let board_info = next_account_info(account_info_iter)?;
+        let task_info = next_account_info(account_info_iter)?;
+        let submission_info = next_account_info(account_info_iter)?;
+        let submitter_info = next_account_info(account_info_iter)?;
+
+        // Verify closer signature
+        if !closer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify accounts
+        if task_info.owner != program_id || submission_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let mut board = Board::load(&board_info.data.borrow())?;
+        let mut task = Task::load_in(&task_info.data.borrow(), board_info.key)?;
+        let submission = Submission::load_in(&submission_info.data.borrow(), task_info.key)?;
+        if task.board != *board_info.key || submission.task != *task_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if submission.submitter != *submitter_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Only the submitter may close before the task has finished
+        let task_finished = task.is_finished();
+        if !task_finished && *closer_info.key != submission.submitter {
+            return Err(BountyBoardError::TaskNotFinished.into());
+        }
+        if !submission.is_finalized(task_finished) {
+            return Err(BountyBoardError::SubmissionNotFinalized.into());
+        }
+
+        // Vesting rewards must have been fully released or clawed back
+        let approved = submission.status == SubmissionStatus::Approved;
+        if approved && task.vesting.is_some() {
+            let vesting_info = next_account_info(account_info_iter)?;
+            let vesting = Self::load_vesting(program_id, submission_info, vesting_info)?;
+            if !vesting.clawed_back && vesting.released < vesting.total {
+                return Err(BountyBoardError::SubmissionNotFinalized.into());
+            }
+        }
+
+        // Archive the submission
+        task.open_submissions = task.open_submissions.saturating_sub(1);
+        board.archive.submissions_closed += 1;
+        if approved {
+            board.archive.submissions_approved += 1;
+        }
+        task.save(task_info)?;
+        board.save(board_info)?;
+
+        Self::close_account(submission_info, submitter_info)?;
+
+        BountyBoardEvent::SubmissionClosed {
+            task: *task_info.key,
+            submission: *submission_info.key,
+            status: submission.status,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    /// Closes a program account, sending its lamports to `recipient_info`
+    fn close_account(account_info: &AccountInfo, recipient_info: &AccountInfo) -> ProgramResult {
+        let lamports = account_info.lamports();
+        **account_info.try_borrow_mut_lamports()? = 0;
+        **recipient_info.try_borrow_mut_lamports()? += lamports;
+        account_info.realloc(0, false)?;
+        account_info.assign(&system_program::id());
+        Ok(())
+    }
+
+    fn process_migrate_account(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        kind: AccountKind,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let account_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify payer signature
+        if !payer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify account ownership
+        if account_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Only the original program's accounts lack a header
+        if let Some(header) = AccountHeader::read(&account_info.data.borrow()) {
+            if header.kind != kind {
+                return Err(BountyBoardError::InvalidAccountKind.into());
+            }
+            return Err(BountyBoardError::AccountAlreadyMigrated.into());
+        }
+
+        // Rewrite the account in the current layout, tying tasks and
+        // submissions to the board vouched for by its creator
+        match kind {
+            AccountKind::Board => {
+                let legacy = legacy::Board::try_from_slice(&account_info.data.borrow())?;
+                if !legacy.is_initialized || legacy.creator != *payer_info.key {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let mut board = Board::from_legacy(legacy);
+                let (authority, authority_bump) =
+                    Self::find_authority(program_id, account_info.key);
+                board.authority_bump = authority_bump;
+                board.vault = get_associated_token_address_with_program_id(
+                    &authority,
+                    &board.reward_token,
+                    &board.token_program,
+                );
+                Self::rewrite(&board, account_info, payer_info, system_program_info)?;
+            }
+            AccountKind::Task => {
+                let board_info = next_account_info(account_info_iter)?;
+                let board = Self::load_owned::<Board>(program_id, board_info)?;
+                let legacy = legacy::Task::try_from_slice(&account_info.data.borrow())?;
+                if board.creator != *payer_info.key || legacy.creator != board.creator {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let task = Task::from_legacy(legacy, board_info.key);
+                Self::rewrite(&task, account_info, payer_info, system_program_info)?;
+            }
+            AccountKind::Submission => {
+                let board_info = next_account_info(account_info_iter)?;
+                let task_info = next_account_info(account_info_iter)?;
+                let board = Self::load_owned::<Board>(program_id, board_info)?;
+                let task = Self::load_owned::<Task>(program_id, task_info)?;
+                if board.creator != *payer_info.key || task.board != *board_info.key {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let legacy = legacy::Submission::try_from_slice(&account_info.data.borrow())?;
+                let submission = Submission::from_legacy(legacy, task_info.key);
+                Self::rewrite(&submission, account_info, payer_info, system_program_info)?;
+            }
+            _ => return Err(BountyBoardError::InvalidAccountKind.into()),
+        }
+
+        BountyBoardEvent::AccountMigrated {
+            account: *account_info.key,
+            kind,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    /// Grows a migrated account to the most its kind can grow to, keeping
+    /// it rent exempt at the payer's expense, and saves it
+    fn rewrite<'a, T: Versioned>(
+        account: &T,
+        account_info: &AccountInfo<'a>,
+        payer_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let len = T::MAX_LEN.max(account_info.data_len());
+        let required = Rent::get()?.minimum_balance(len);
+        let shortfall = required.saturating_sub(account_info.lamports());
+        if shortfall > 0 {
+            invoke(
+                &system_instruction::transfer(payer_info.key, account_info.key, shortfall),
+                &[
+                    payer_info.clone(),
+                    account_info.clone(),
+                    system_program_info.clone(),
+                ],
+            )?;
+        }
+
+        account_info.realloc(len, true)?;
+        account.save(account_info)
+    }
+
+    /// Checks a client-created account is the program's, unused and
+    /// allocated the most an account of kind `T` can grow to
+    fn verify_new_account<T: Versioned>(
+        program_id: &Pubkey,
+        account_info: &AccountInfo,
+    ) -> ProgramResult {
+        if account_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if account_info.data.borrow().iter().any(|byte| *byte != 0) {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+        if account_info.data_len() < T::MAX_LEN {
+            return Err(BountyBoardError::AccountTooSmall.into());
+        }
+        Ok(())
+    }
+
+    /// Creates the `index`th submission account of a task at
+    /// `[b"submission", task, index]`, paid for by the submitter
+    fn create_submission<'a>(
+        program_id: &Pubkey,
+        task_key: &Pubkey,
+        index: u64,
+        submitter_info: &AccountInfo<'a>,
+        submission_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        let index = index.to_le_bytes();
+        let (submission_pda, bump_seed) = Pubkey::find_program_address(
+            &[b"submission", task_key.as_ref(), &index],
+            program_id,
+        );
+        if submission_pda != *submission_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !submission_info.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        let space = Submission::MAX_LEN;
+        let rent = Rent::get()?;
+        invoke_signed(
+            &system_instruction::create_account(
+                submitter_info.key,
+                &submission_pda,
+                rent.minimum_balance(space),
+                space as u64,
+                program_id,
+            ),
+            &[
+                submitter_info.clone(),
+                submission_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[b"submission", task_key.as_ref(), &index, &[bump_seed]]],
+        )
+    }
+
+    /// Loads a program account of kind `T`
+    fn load_owned<T: Versioned>(
+        program_id: &Pubkey,
+        account_info: &AccountInfo,
+    ) -> Result<T, ProgramError> {
+        if account_info.owner != program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        T::load(&account_info.data.borrow())
+    }
+
+    fn process_initialize_program_config(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        admin: Pubkey,
+        fees: ProtocolFees,
+        features: FeatureFlags,
+        limits: ProgramLimits,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let authority_info = next_account_info(account_info_iter)?;
+        let config_info = next_account_info(account_info_iter)?;
+        let program_data_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify authority signature
+        if !authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Verify the signer is the program's upgrade authority
+        let (program_data, _) = Pubkey::find_program_address(
+            &[program_id.as_ref()],
+            &bpf_loader_upgradeable::id(),
+        );
+        if *program_data_info.key != program_data {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let upgrade_authority = Self::upgrade_authority(&program_data_info.data.borrow());
+        if upgrade_authority != Some(*authority_info.key) {
+            return Err(BountyBoardError::NotUpgradeAuthority.into());
+        }
+
+        // Create the config PDA, once
+        let (config_pda, bump_seed) = Pubkey::find_program_address(&[b"config"], program_id);
+        if config_pda != *config_info.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if !config_info.data_is_empty() {
+            return Err(BountyBoardError::ProgramConfigAlreadyInitialized.into());
+        }
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_info.key,
+                &config_pda,
+                Rent::get()?.minimum_balance(ProgramConfig::LEN),
+                ProgramConfig::LEN as u64,
+                program_id,
+            ),
+            &[
+                authority_info.clone(),
+                config_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[b"config", &[bump_seed]]],
+        )?;
+
+        let mut program_config = ProgramConfig::default();
+        program_config.header = ProgramConfig::header();
+        program_config.is_initialized = true;
+        program_config.admin = admin;
+        program_config.upgrade_authority = *authority_info.key;
+        program_config.fees = fees;
+        program_config.features = features;
+        program_config.limits = limits;
+        Self::save_program_config(&program_config, config_info)
+    }
+
+    /// Reads the upgrade authority from a program data account, laid out
+    /// as a u32 tag of 3, a u64 slot and an optional authority
+    fn upgrade_authority(data: &[u8]) -> Option<Pubkey> {
+        if data.len() < 45 || data[..4] != 3u32.to_le_bytes() || data[12] != 1 {
+            return None;
+        }
+        Pubkey::try_from(&data[13..45]).ok()
+    }
+
+    fn load_program_config(
+        program_id: &Pubkey,
+        config_info: &AccountInfo,
+    ) -> Result<ProgramConfig, ProgramError> {
+        let (config_pda, _) = Pubkey::find_program_address(&[b"config"], program_id);
+        if *config_info.key != config_pda || config_info.owner != program_id {
+            return Err(BountyBoardError::InvalidProgramConfig.into());
+        }
+        let program_config = ProgramConfig::load(&config_info.data.borrow())?;
+        if !program_config.is_initialized {
+            return Err(BountyBoardError::InvalidProgramConfig.into());
+        }
+
+        Ok(program_config)
+    }
 
+    fn save_program_config(
+        program_config: &ProgramConfig,
+        config_info: &AccountInfo,
+    ) -> ProgramResult {
+        program_config.save(config_info)?;
+
+        BountyBoardEvent::ProgramConfigUpdated {
+            admin: program_config.admin,
+            paused: program_config.paused,
+        }
+        .emit()?;
+
+        Ok(())
+    }
+
+    /// Verifies the first account is the admin's signature
+    fn verify_admin(program_config: &ProgramConfig, accounts: &[AccountInfo]) -> ProgramResult {
+        let admin_info = next_account_info(&mut accounts.iter())?;
+        if !admin_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if *admin_info.key != program_config.admin {
+            return Err(BountyBoardError::NotProgramAdmin.into());
+        }
+        Ok(())
+    }
+
+    /// Rejects instructions while the program is paused, or when they use
+    /// a feature the admin switched off
+    fn verify_enabled(
+        program_config: &ProgramConfig,
+        instruction: &BountyBoardInstruction,
+    ) -> ProgramResult {
+        let admin = matches!(
+            instruction,
+            BountyBoardInstruction::UpdateProgramConfig { .. }
+                | BountyBoardInstruction::SetProgramAdmin { .. }
+                | BountyBoardInstruction::SetPaused { .. }
+        );
+        if program_config.paused && !admin {
+            return Err(BountyBoardError::ProgramPaused.into());
+        }
+
+        let features = &program_config.features;
+        let enabled = match instruction {
+            BountyBoardInstruction::CreateTask {
+                kind,
+                vesting,
+                recurrence,
+                config,
+                ..
+            } => {
+                (*kind != TaskKind::Contest || features.contests)
+                    && (*kind != TaskKind::Milestone || features.milestones)
+                    && (vesting.is_none() || features.vesting)
+                    && (recurrence.is_none() || features.recurring_tasks)
+                    && (!config.blind_voting || features.commit_reveal)
+            }
+            BountyBoardInstruction::SubmitProof {
+                encrypted_proof, ..
+            }
+            | BountyBoardInstruction::SubmitMilestone {
+                encrypted_proof, ..
+            }
+            | BountyBoardInstruction::RevealProof {
+                encrypted_proof, ..
+            } => encrypted_proof.is_none() || features.encrypted_proofs,
+            BountyBoardInstruction::CommitProof { .. } => features.commit_reveal,
+            BountyBoardInstruction::OpenDispute | BountyBoardInstruction::DisputeVesting => {
+                features.disputes
+            }
+            _ => true,
+        };
+        if !enabled {
+            return Err(BountyBoardError::FeatureDisabled.into());
+        }
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Transfers a protocol fee in lamports to the config's fee recipient
+    fn charge_fee<'a>(
+        program_config: &ProgramConfig,
+        fee: u64,
+        payer_info: &AccountInfo<'a>,
+        recipient_info: &AccountInfo<'a>,
+        system_program_info: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        if *recipient_info.key != program_config.fees.recipient {
+            return Err(BountyBoardError::InvalidFeeRecipient.into());
+        }
+        invoke(
+            &system_instruction::transfer(payer_info.key, recipient_info.key, fee),
+            &[
+                payer_info.clone(),
+                recipient_info.clone(),
+                system_program_info.clone(),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+
+    fn program_data(upgrade_authority_address: Option<Pubkey>) -> Vec<u8> {
+        let mut data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 42,
+            upgrade_authority_address,
+        })
+        .unwrap();
+        // The program's bytecode follows the metadata
+        data.extend([0xff; 16]);
+        data
+    }
+
+    #[test]
+    fn upgrade_authority_reads_program_data() {
+        let authority = Pubkey::new_unique();
+        assert_eq!(
+            Processor::upgrade_authority(&program_data(Some(authority))),
+            Some(authority)
+        );
+        // Immutable programs have no authority
+        assert_eq!(Processor::upgrade_authority(&program_data(None)), None);
+
+        // Other loader states and truncated data are rejected
+        let program = bincode::serialize(&UpgradeableLoaderState::Program {
+            programdata_address: authority,
+        })
+        .unwrap();
+        assert_eq!(Processor::upgrade_authority(&program), None);
+        let data = program_data(Some(authority));
+        assert_eq!(Processor::upgrade_authority(&data[..44]), None);
+    }
+
+    fn commit_proof() -> BountyBoardInstruction {
+        BountyBoardInstruction::CommitProof {
+            commitment: [1; 32],
+            contributors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn paused_program_only_takes_admin_instructions() {
+        let mut program_config = ProgramConfig::default();
+        program_config.paused = true;
+
+        assert_eq!(
+            Processor::verify_enabled(&program_config, &BountyBoardInstruction::ClaimReward),
+            Err(BountyBoardError::ProgramPaused.into())
+        );
+        assert_eq!(
+            Processor::verify_enabled(&program_config, &commit_proof()),
+            Err(BountyBoardError::ProgramPaused.into())
+        );
+        let unpause = BountyBoardInstruction::SetPaused { paused: false };
+        assert_eq!(Processor::verify_enabled(&program_config, &unpause), Ok(()));
+    }
+
+    #[test]
+    fn disabled_features_reject_their_instructions() {
+        let mut program_config = ProgramConfig::default();
+        assert_eq!(Processor::verify_enabled(&program_config, &commit_proof()), Ok(()));
+
+        program_config.features.commit_reveal = false;
+        program_config.features.disputes = false;
+        program_config.features.encrypted_proofs = false;
+        assert_eq!(
+            Processor::verify_enabled(&program_config, &commit_proof()),
+            Err(BountyBoardError::FeatureDisabled.into())
+        );
+        assert_eq!(
+            Processor::verify_enabled(&program_config, &BountyBoardInstruction::DisputeVesting),
+            Err(BountyBoardError::FeatureDisabled.into())
+        );
+
+        // Plain proofs need no feature, encrypted ones do
+        let submit = |encrypted_proof| BountyBoardInstruction::SubmitProof {
+            proof: "https://example.com/proof".to_string(),
+            contributors: Vec::new(),
+            proof_ref: None,
+            encrypted_proof,
+        };
+        assert_eq!(Processor::verify_enabled(&program_config, &submit(None)), Ok(()));
+        let encrypted = EncryptedProof {
+            ciphertext: vec![1; 16],
+            nonce: [0; 12],
+            ephemeral_key: [0; 32],
+            wrapped_keys: Vec::new(),
+        };
+        assert_eq!(
+            Processor::verify_enabled(&program_config, &submit(Some(encrypted))),
+            Err(BountyBoardError::FeatureDisabled.into())
+        );
+    }
+
+    #[test]
+    fn admin_and_fee_recipient_must_match_config() {
+        let mut program_config = ProgramConfig::default();
+        program_config.admin = Pubkey::new_unique();
+        program_config.fees.recipient = Pubkey::new_unique();
+
+        let other = Pubkey::new_unique();
+        let owner = system_program::id();
+        let (mut admin_lamports, mut other_lamports, mut system_lamports) = (0, 0, 0);
+        let (mut admin_data, mut other_data, mut system_data) = (vec![], vec![], vec![]);
+        let admin = program_config.admin;
+        let admin_info = AccountInfo::new(
+            &admin,
+            true,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &owner,
+            false,
+            0,
+        );
+        let other_info = AccountInfo::new(
+            &other,
+            true,
+            true,
+            &mut other_lamports,
+            &mut other_data,
+            &owner,
+            false,
+            0,
+        );
+        let system_info = AccountInfo::new(
+            &owner,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &owner,
+            true,
+            0,
+        );
+
+        assert_eq!(
+            Processor::verify_admin(&program_config, std::slice::from_ref(&admin_info)),
+            Ok(())
+        );
+        assert_eq!(
+            Processor::verify_admin(&program_config, std::slice::from_ref(&other_info)),
+            Err(BountyBoardError::NotProgramAdmin.into())
+        );
+        let mut unsigned = admin_info.clone();
+        unsigned.is_signer = false;
+        assert_eq!(
+            Processor::verify_admin(&program_config, &[unsigned]),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // Fees only go to the configured recipient
+        assert_eq!(
+            Processor::charge_fee(&program_config, 10, &admin_info, &other_info, &system_info),
+            Err(BountyBoardError::InvalidFeeRecipient.into())
+        );
+    }
+}
@@ -1,26 +1,126 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    hash::{hash, hashv},
+    program_error::ProgramError,
     program_pack::{IsInitialized, Sealed},
     pubkey::Pubkey,
 };
 
-#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+use crate::error::BountyBoardError;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
 pub struct Board {
+    pub header: AccountHeader,
     pub is_initialized: bool,
     pub creator: Pubkey,
     pub name: String,
     pub description: String,
     pub img: String,
     pub reward_token: Pubkey,
+    pub token_program: Pubkey,
+    pub reward_asset: RewardAsset,
+    /// The token account holding the board's own reward token, or the
+    /// lamport vault PDA of SOL boards
+    pub vault: Pubkey,
+    /// Bump of the `[b"authority", board]` PDA owning the board's token accounts
+    pub authority_bump: u8,
     pub total_pledged: u64,
+    pub reserved: u64,
     pub created_at: i64,
     pub closed: bool,
-    pub config: String,
+    pub metadata: String,
+    pub config: BoardConfig,
+    pub arbiters: Vec<Pubkey>,
+    pub dispute_bond: u64,
+    pub slash_destination: Pubkey,
+    pub pools: Vec<TokenPool>,
+    pub archive: BoardArchive,
+}
+
+/// What remains on the board of its closed task and submission accounts
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct BoardArchive {
+    pub tasks_closed: u64,
+    pub submissions_closed: u64,
+    pub submissions_approved: u64,
+    pub completions: u64,
+}
+
+/// What a board's pledges and rewards are paid in
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum RewardAsset {
+    /// SPL tokens of `reward_token`, held in the vault authority's
+    /// associated token account
+    #[default]
+    Token,
+    /// Lamports, held in the board's vault PDA
+    Sol,
+}
+
+/// Board rules enforced by the program
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct BoardConfig {
+    pub version: u8,
+    pub join_policy: JoinPolicy,
+    /// Reviewers a task may have, 0 for no limit
+    pub max_reviewers_per_task: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum JoinPolicy {
+    #[default]
+    Open,
+    /// The board creator co-signs every `JoinBoard`
+    CreatorApproval,
+    Closed,
+}
+
+/// Task rules enforced by the program
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct TaskConfig {
+    pub version: u8,
+    pub review_policy: ReviewPolicy,
+    pub self_check_policy: SelfCheckPolicy,
+    /// Submissions the task accepts, 0 for no limit
+    pub max_submissions: u64,
+    /// Reviewers commit to their votes before any is revealed; requires a
+    /// task deadline
+    pub blind_voting: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum ReviewPolicy {
+    #[default]
+    AnyReviewer,
+    /// Reviewers may not vote on submissions they submitted or contributed to
+    ExcludeParticipants,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum SelfCheckPolicy {
+    #[default]
+    Disabled,
+    Enabled,
+}
+
+/// Pledges in an additional reward mint, held in the vault authority's
+/// associated token account for that mint
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct TokenPool {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub vault: Pubkey,
+    pub total_pledged: u64,
+    pub reserved: u64,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Task {
+    pub header: AccountHeader,
     pub id: u64,
+    pub board: Pubkey,
     pub name: String,
     pub creator: Pubkey,
     pub description: String,
@@ -32,8 +132,108 @@ pub struct Task {
     pub reward_amount: u64,
     pub created_at: i64,
     pub cancelled: bool,
-    pub config: String,
-    pub allow_self_check: bool,
+    pub metadata: String,
+    pub config: TaskConfig,
+    pub eligibility: Eligibility,
+    pub approval_threshold: u8,
+    pub submission_deposit: u64,
+    pub deposit_kind: DepositKind,
+    pub reward_tiers: Vec<u64>,
+    pub kind: TaskKind,
+    pub prize_shares_bps: Vec<u16>,
+    pub ranking: Vec<Pubkey>,
+    pub reward_mint: Pubkey,
+    pub vesting: Option<VestingSchedule>,
+    pub milestone_shares_bps: Vec<u16>,
+    pub recurrence: Option<Recurrence>,
+    pub cycle: u32,
+    pub review_grace_period: i64,
+    pub expiry_policy: ExpiryPolicy,
+    pub expired: bool,
+    pub reserved: u64,
+    pub num_submissions: u64,
+    /// Off-chain description, for tasks not storing it in `description`
+    pub description_ref: Option<ContentRef>,
+    /// Submission accounts not yet closed; the task closes once none remain
+    pub open_submissions: u64,
+    /// Whether `open_submissions` counts every submission account; tasks
+    /// migrated from the original program did not count theirs
+    pub submissions_tracked: bool,
+}
+
+/// How `ExpireTask` resolves submissions still pending at expiry
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum ExpiryPolicy {
+    #[default]
+    RejectPending,
+    /// Approve with the full reward while completions remain
+    ApprovePending,
+}
+
+/// Reopens a task every `period` seconds after its deadline, for `cycles`
+/// cycles in total including the first
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Recurrence {
+    pub period: i64,
+    pub cycles: u32,
+}
+
+/// Linear release of approved rewards, in seconds from approval.
+/// Nothing vests before `cliff`; everything has vested after `duration`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct VestingSchedule {
+    pub cliff: i64,
+    pub duration: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum TaskKind {
+    /// Submissions are approved one by one until `max_completions`
+    #[default]
+    Standard,
+    /// Submissions accumulate until the deadline, then `reward_amount` is
+    /// split by place according to `prize_shares_bps`
+    Contest,
+    /// Each submission is reviewed and paid once per milestone, with
+    /// `reward_amount` split according to `milestone_shares_bps`
+    Milestone,
+}
+
+/// What a task's submission deposit is paid in
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum DepositKind {
+    #[default]
+    Lamports,
+    RewardToken,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MemberRole {
+    Member,
+    Contributor,
+    Moderator,
+}
+
+/// Optional constraints on who may submit to a task.
+/// Every constraint left at its default is not enforced.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Eligibility {
+    pub min_reputation: u64,
+    pub required_role: Option<MemberRole>,
+    pub allowlist: Vec<Pubkey>,
+    pub joined_before_task: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Member {
+    pub header: AccountHeader,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub user: Pubkey,
+    pub joined_at: i64,
+    pub reputation: u64,
+    pub role: MemberRole,
+    pub payout_address: Pubkey,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
@@ -41,15 +241,532 @@ pub enum SubmissionStatus {
     Pending,
     Approved,
     Rejected,
+    Disputed,
+    /// Only a commitment has been published; `RevealProof` makes it pending
+    Committed,
+}
+
+/// A co-contributor's share of a submission's reward, in basis points
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Contributor {
+    pub key: Pubkey,
+    pub share_bps: u16,
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct Submission {
+    pub header: AccountHeader,
+    pub task: Pubkey,
     pub submitter: Pubkey,
     pub proof: String,
     pub status: SubmissionStatus,
     pub submitted_at: i64,
     pub review_comment: String,
+    pub approvals: u8,
+    pub rejections: u8,
+    pub spam_votes: u8,
+    pub deposit: u64,
+    pub approved_amount: u64,
+    pub contributors: Vec<Contributor>,
+    pub payouts: Vec<Payout>,
+    pub milestone: u8,
+    pub review_round: u8,
+    /// Off-chain proof, for submissions not storing it in `proof`
+    pub proof_ref: Option<ContentRef>,
+    /// Proof readable only by the task's reviewers at submission time
+    pub encrypted_proof: Option<EncryptedProof>,
+    /// Set by `CommitProof`; `submitted_at` is then the commit time
+    pub commitment: Option<[u8; 32]>,
+    pub revealed_at: i64,
+    /// Votes committed in the current review round of a blind voting task
+    pub vote_commits: u8,
+    /// Reviewers of the task when the round's first vote was committed,
+    /// all of whom must commit before reveals open ahead of the deadline
+    pub commit_reviewers: u8,
+    /// The task cycle the submission was made in
+    pub cycle: u32,
+}
+
+/// Content stored off-chain at `uri`, pinned by the SHA-256 `hash` of its
+/// bytes so clients can detect tampering
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct ContentRef {
+    pub uri: String,
+    pub hash: [u8; 32],
+}
+
+/// Proof encrypted under a one-time content key, which is wrapped for
+/// every reviewer with a key agreed from `ephemeral_key` and the
+/// reviewer's X25519 form of their wallet key
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct EncryptedProof {
+    pub ciphertext: Vec<u8>,
+    pub nonce: [u8; 12],
+    pub ephemeral_key: [u8; 32],
+    pub wrapped_keys: Vec<WrappedKey>,
+}
+
+/// The content key of an encrypted proof, sealed for one reviewer
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WrappedKey {
+    pub reviewer: Pubkey,
+    pub key: [u8; 48],
+}
+
+/// Tokens owed to a payee of a submission, paid out by `ClaimReward`
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Payout {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub claimed: bool,
+}
+
+/// The reward a reviewer approves a submission with
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RewardChoice {
+    Full,
+    Custom(u64),
+    Tier(u8),
+}
+
+/// Marks that a reviewer has voted on a submission.
+/// The vote itself is only tallied on the submission.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct VoteRecord {
+    pub header: AccountHeader,
+    pub is_initialized: bool,
+    pub submission: Pubkey,
+    pub reviewer: Pubkey,
+    pub voted_at: i64,
+    /// Set by `CommitVote`; the vote is tallied once revealed
+    pub commitment: Option<[u8; 32]>,
+    pub revealed: bool,
+}
+
+/// Tracks the release of a vesting task's reward to an approved submission.
+/// Released amounts are credited to the submission's payouts.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Vesting {
+    pub header: AccountHeader,
+    pub is_initialized: bool,
+    pub submission: Pubkey,
+    pub total: u64,
+    pub released: u64,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+    pub clawed_back: bool,
+}
+
+/// Program-wide settings held by the `[b"config"]` PDA, read ahead of
+/// every instruction
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct ProgramConfig {
+    pub header: AccountHeader,
+    pub is_initialized: bool,
+    pub admin: Pubkey,
+    /// The upgrade authority that initialized the config
+    pub upgrade_authority: Pubkey,
+    pub paused: bool,
+    pub fees: ProtocolFees,
+    pub features: FeatureFlags,
+    pub limits: ProgramLimits,
+}
+
+/// Lamport fees charged to creators and paid to `recipient`
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ProtocolFees {
+    pub recipient: Pubkey,
+    pub board_fee: u64,
+    pub task_fee: u64,
+}
+
+/// Features the admin may switch off program-wide
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FeatureFlags {
+    pub contests: bool,
+    pub milestones: bool,
+    pub vesting: bool,
+    pub recurring_tasks: bool,
+    pub disputes: bool,
+    pub encrypted_proofs: bool,
+    pub commit_reveal: bool,
+}
+
+/// Program-wide limits; zero leaves a limit unset
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct ProgramLimits {
+    pub max_reward_amount: u64,
+    pub max_reviewers_per_task: u8,
+    pub max_pools_per_board: u8,
+}
+
+/// The kinds of account owned by the program
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AccountKind {
+    Board,
+    Task,
+    Submission,
+    Member,
+    VoteRecord,
+    Vesting,
+    Dispute,
+    ProgramConfig,
+}
+
+/// Leads every account: a fixed marker, the account's kind and the
+/// version of its layout. Accounts of the original program have none.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct AccountHeader {
+    pub marker: [u8; 4],
+    pub kind: AccountKind,
+    pub version: u8,
+}
+
+impl AccountHeader {
+    pub const MARKER: [u8; 4] = *b"BNTY";
+    pub const LEN: usize = 4 + 1 + 1;
+
+    /// Reads the header of `data`, if it has one
+    pub fn read(data: &[u8]) -> Option<Self> {
+        let header = Self::deserialize(&mut &data[..]).ok()?;
+        (header.marker == Self::MARKER).then_some(header)
+    }
+}
+
+/// An unset header, which `Versioned::load` rejects
+impl Default for AccountHeader {
+    fn default() -> Self {
+        Self {
+            marker: [0; 4],
+            kind: AccountKind::Board,
+            version: 0,
+        }
+    }
+}
+
+/// Accounts begin with an `AccountHeader`, and later versions of a layout
+/// are converted on `MigrateAccount`. Version 0 is the headerless layout
+/// of the original program, which only had boards, tasks and submissions;
+/// it is converted in memory when loaded, but only `MigrateAccount` grows
+/// the account so it can be saved.
+pub trait Versioned: BorshSerialize + BorshDeserialize {
+    const KIND: AccountKind;
+    const VERSION: u8;
+    /// The most the account can serialize to, which it is allocated
+    const MAX_LEN: usize;
+
+    fn header() -> AccountHeader {
+        AccountHeader {
+            marker: AccountHeader::MARKER,
+            kind: Self::KIND,
+            version: Self::VERSION,
+        }
+    }
+
+    /// Converts an account in the original program's layout, which did not
+    /// record the board or task it belongs to: its `parent`, when known
+    fn upgrade(_data: &[u8], _parent: Option<&Pubkey>) -> Result<Self, ProgramError> {
+        Err(BountyBoardError::AccountNotMigrated.into())
+    }
+
+    /// Reads an account of this kind in the current layout. Accounts may
+    /// be allocated larger than their data, so trailing bytes are ignored.
+    fn load(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::load_with_parent(data, None)
+    }
+
+    /// Reads an account like `load`, taking an account of the original
+    /// program to belong to `parent`
+    fn load_in(data: &[u8], parent: &Pubkey) -> Result<Self, ProgramError> {
+        Self::load_with_parent(data, Some(parent))
+    }
+
+    fn load_with_parent(data: &[u8], parent: Option<&Pubkey>) -> Result<Self, ProgramError> {
+        match AccountHeader::read(data) {
+            Some(header) if header.kind != Self::KIND => {
+                Err(BountyBoardError::InvalidAccountKind.into())
+            }
+            Some(header) if header.version != Self::VERSION => {
+                Err(BountyBoardError::AccountNotMigrated.into())
+            }
+            Some(_) => Ok(Self::deserialize(&mut &data[..])?),
+            None if data.iter().all(|byte| *byte == 0) => Err(ProgramError::UninitializedAccount),
+            None => Self::upgrade(data, parent),
+        }
+    }
+
+    /// Writes the account, zeroing what it no longer uses
+    fn save(&self, account_info: &AccountInfo) -> ProgramResult {
+        let data = self.try_to_vec()?;
+        let mut account_data = account_info.data.borrow_mut();
+        if data.len() > account_data.len() {
+            return Err(BountyBoardError::AccountTooSmall.into());
+        }
+        account_data[..data.len()].copy_from_slice(&data);
+        account_data[data.len()..].fill(0);
+        Ok(())
+    }
+}
+
+impl Versioned for Board {
+    const KIND: AccountKind = AccountKind::Board;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = AccountHeader::LEN
+        + 1
+        + 32
+        + (4 + Self::MAX_NAME_LEN)
+        + (4 + Self::MAX_DESCRIPTION_LEN)
+        + (4 + Self::MAX_IMG_LEN)
+        + 32
+        + 32
+        + 1
+        + 32
+        + 1
+        + 8
+        + 8
+        + 8
+        + 1
+        + (4 + Self::MAX_METADATA_LEN)
+        + BoardConfig::LEN
+        + (4 + 32 * Self::MAX_ARBITERS)
+        + 8
+        + 32
+        + (4 + TokenPool::LEN * Self::MAX_POOLS)
+        + BoardArchive::LEN;
+
+    fn upgrade(data: &[u8], _parent: Option<&Pubkey>) -> Result<Self, ProgramError> {
+        Ok(Self::from_legacy(legacy::Board::deserialize(&mut &data[..])?))
+    }
+}
+
+impl Versioned for Task {
+    const KIND: AccountKind = AccountKind::Task;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = AccountHeader::LEN
+        + 8
+        + 32
+        + (4 + Self::MAX_NAME_LEN)
+        + 32
+        + (4 + Self::MAX_DESCRIPTION_LEN)
+        + 8
+        + 8
+        + 8
+        + (4 + 32 * Self::MAX_REVIEWERS)
+        + 1
+        + 8
+        + 8
+        + 1
+        + (4 + Self::MAX_METADATA_LEN)
+        + TaskConfig::LEN
+        + Eligibility::MAX_LEN
+        + 1
+        + 8
+        + 1
+        + (4 + 8 * Self::MAX_REWARD_TIERS)
+        + 1
+        + (4 + 2 * Self::MAX_PRIZES)
+        + (4 + 32 * Self::MAX_PRIZES)
+        + 32
+        + (1 + VestingSchedule::LEN)
+        + (4 + 2 * Self::MAX_MILESTONES)
+        + (1 + Recurrence::LEN)
+        + 4
+        + 8
+        + 1
+        + 1
+        + 8
+        + 8
+        + (1 + ContentRef::MAX_LEN)
+        + 8
+        + 1;
+
+    fn upgrade(data: &[u8], parent: Option<&Pubkey>) -> Result<Self, ProgramError> {
+        let board = parent.ok_or(BountyBoardError::AccountNotMigrated)?;
+        Ok(Self::from_legacy(legacy::Task::deserialize(&mut &data[..])?, board))
+    }
+}
+
+impl Versioned for Submission {
+    const KIND: AccountKind = AccountKind::Submission;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = AccountHeader::LEN
+        + 32
+        + 32
+        + (4 + Self::MAX_PROOF_LEN)
+        + 1
+        + 8
+        + (4 + Self::MAX_COMMENT_LEN)
+        + 3
+        + 8
+        + 8
+        + (4 + Contributor::LEN * Self::MAX_CONTRIBUTORS)
+        + (4 + Payout::LEN * Self::MAX_PAYOUTS)
+        + 1
+        + 1
+        + (1 + ContentRef::MAX_LEN)
+        + (1 + EncryptedProof::MAX_LEN)
+        + (1 + 32)
+        + 8
+        + 1
+        + 1
+        + 4;
+
+    fn upgrade(data: &[u8], parent: Option<&Pubkey>) -> Result<Self, ProgramError> {
+        let task = parent.ok_or(BountyBoardError::AccountNotMigrated)?;
+        Ok(Self::from_legacy(legacy::Submission::deserialize(&mut &data[..])?, task))
+    }
+}
+
+impl Versioned for Member {
+    const KIND: AccountKind = AccountKind::Member;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = Self::LEN;
+}
+
+impl Versioned for VoteRecord {
+    const KIND: AccountKind = AccountKind::VoteRecord;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = Self::LEN;
+}
+
+impl Versioned for Vesting {
+    const KIND: AccountKind = AccountKind::Vesting;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = Self::LEN;
+}
+
+impl Versioned for Dispute {
+    const KIND: AccountKind = AccountKind::Dispute;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = Self::LEN;
+}
+
+impl Versioned for ProgramConfig {
+    const KIND: AccountKind = AccountKind::ProgramConfig;
+    const VERSION: u8 = 1;
+    const MAX_LEN: usize = Self::LEN;
+}
+
+/// The layouts of the original program, which `MigrateAccount` converts
+pub mod legacy {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::pubkey::Pubkey;
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+    pub struct Board {
+        pub is_initialized: bool,
+        pub creator: Pubkey,
+        pub name: String,
+        pub description: String,
+        pub img: String,
+        pub reward_token: Pubkey,
+        pub total_pledged: u64,
+        pub created_at: i64,
+        pub closed: bool,
+        pub config: String,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+    pub struct Task {
+        pub id: u64,
+        pub name: String,
+        pub creator: Pubkey,
+        pub description: String,
+        pub deadline: i64,
+        pub max_completions: u64,
+        pub num_completions: u64,
+        pub reviewers: Vec<Pubkey>,
+        pub completed: bool,
+        pub reward_amount: u64,
+        pub created_at: i64,
+        pub cancelled: bool,
+        pub config: String,
+        pub allow_self_check: bool,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+    pub enum SubmissionStatus {
+        Pending,
+        Approved,
+        Rejected,
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+    pub struct Submission {
+        pub submitter: Pubkey,
+        pub proof: String,
+        pub status: SubmissionStatus,
+        pub submitted_at: i64,
+        pub review_comment: String,
+    }
+}
+
+impl Board {
+    /// Converts a board of the original program. Its pledges sit in token
+    /// accounts the program never controlled, so they are not carried over.
+    pub fn from_legacy(legacy: legacy::Board) -> Self {
+        let mut board = Board::default();
+        board.header = Board::header();
+        board.is_initialized = legacy.is_initialized;
+        board.creator = legacy.creator;
+        board.name = legacy.name;
+        board.description = legacy.description;
+        board.img = legacy.img;
+        board.reward_token = legacy.reward_token;
+        board.token_program = spl_token::id();
+        board.created_at = legacy.created_at;
+        board.closed = legacy.closed;
+        board.metadata = legacy.config;
+        board
+    }
+}
+
+impl Task {
+    /// Converts a task of the original program, which did not record its
+    /// board. Nothing was reserved for it, and its submissions are not
+    /// counted.
+    pub fn from_legacy(legacy: legacy::Task, board: &Pubkey) -> Self {
+        let mut task = Task::default();
+        task.id = legacy.id;
+        task.board = *board;
+        task.name = legacy.name;
+        task.creator = legacy.creator;
+        task.description = legacy.description;
+        task.deadline = legacy.deadline;
+        task.max_completions = legacy.max_completions;
+        task.num_completions = legacy.num_completions;
+        task.reviewers = legacy.reviewers;
+        task.completed = legacy.completed;
+        task.reward_amount = legacy.reward_amount;
+        task.created_at = legacy.created_at;
+        task.cancelled = legacy.cancelled;
+        task.metadata = legacy.config;
+        if legacy.allow_self_check {
+            task.config.self_check_policy = SelfCheckPolicy::Enabled;
+        }
+        task.submissions_tracked = false;
+        task
+    }
+}
+
+impl Submission {
+    /// Converts a submission of the original program, which did not record
+    /// its task. Approved submissions were paid when approved.
+    pub fn from_legacy(legacy: legacy::Submission, task: &Pubkey) -> Self {
+        let mut submission = Submission::default();
+        submission.task = *task;
+        submission.submitter = legacy.submitter;
+        submission.proof = legacy.proof;
+        submission.status = match legacy.status {
+            legacy::SubmissionStatus::Pending => SubmissionStatus::Pending,
+            legacy::SubmissionStatus::Approved => SubmissionStatus::Approved,
+            legacy::SubmissionStatus::Rejected => SubmissionStatus::Rejected,
+        };
+        submission.submitted_at = legacy.submitted_at;
+        submission.review_comment = legacy.review_comment;
+        submission
+    }
 }
 
 impl Sealed for Board {}
@@ -59,27 +776,584 @@ impl IsInitialized for Board {
     }
 }
 
-impl Default for Board {
+impl Sealed for Member {}
+impl IsInitialized for Member {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl Member {
+    pub const LEN: usize = AccountHeader::LEN + 1 + 32 + 32 + 8 + 8 + 1 + 32;
+
+    /// Owners allowed to receive this member's rewards: the member and,
+    /// if designated, their payout address
+    pub fn payout_owners(&self) -> Vec<Pubkey> {
+        let mut owners = vec![self.user];
+        if self.payout_address != Pubkey::default() {
+            owners.push(self.payout_address);
+        }
+        owners
+    }
+}
+
+/// An appeal against a rejected submission, holding the submitter's bond
+/// in lamports until an arbiter resolves it. A clawback dispute instead
+/// challenges an approved vesting submission, without a bond; overturning
+/// it allows `ClawbackVesting`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct Dispute {
+    pub header: AccountHeader,
+    pub is_initialized: bool,
+    pub submission: Pubkey,
+    pub submitter: Pubkey,
+    pub bond: u64,
+    pub opened_at: i64,
+    pub resolved: bool,
+    pub overturned: bool,
+    pub clawback: bool,
+}
+
+impl Board {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_DESCRIPTION_LEN: usize = 1024;
+    pub const MAX_IMG_LEN: usize = 256;
+    pub const MAX_METADATA_LEN: usize = 512;
+    pub const MAX_ARBITERS: usize = 8;
+    pub const MAX_POOLS: usize = 8;
+
+    /// Checks the board's string fields against their byte limits.
+    /// Borsh has already rejected anything that is not UTF-8.
+    pub fn validate_fields(
+        name: &str,
+        description: &str,
+        img: &str,
+        metadata: &str,
+    ) -> Result<(), BountyBoardError> {
+        if name.len() > Self::MAX_NAME_LEN {
+            return Err(BountyBoardError::NameTooLong);
+        }
+        if description.len() > Self::MAX_DESCRIPTION_LEN {
+            return Err(BountyBoardError::DescriptionTooLong);
+        }
+        if img.len() > Self::MAX_IMG_LEN {
+            return Err(BountyBoardError::ImageUrlTooLong);
+        }
+        if !img.is_empty() && !is_valid_url(img) {
+            return Err(BountyBoardError::InvalidImageUrl);
+        }
+        if metadata.len() > Self::MAX_METADATA_LEN {
+            return Err(BountyBoardError::MetadataTooLong);
+        }
+        Ok(())
+    }
+
+    pub fn is_arbiter(&self, key: &Pubkey) -> bool {
+        self.arbiters.contains(key)
+    }
+
+    pub fn pool(&self, mint: &Pubkey) -> Option<&TokenPool> {
+        self.pools.iter().find(|pool| pool.mint == *mint)
+    }
+
+    /// Whether rewards in `reward_mint` are lamports from the board's vault.
+    /// The default key stands for the board's own reward asset.
+    pub fn pays_lamports(&self, reward_mint: &Pubkey) -> bool {
+        *reward_mint == Pubkey::default() && self.reward_asset == RewardAsset::Sol
+    }
+
+    /// The mint and token program of rewards in `reward_mint`
+    pub fn token_for(&self, reward_mint: &Pubkey) -> Result<(Pubkey, Pubkey), BountyBoardError> {
+        if *reward_mint == Pubkey::default() {
+            return Ok((self.reward_token, self.token_program));
+        }
+        self.pool(reward_mint)
+            .map(|pool| (pool.mint, pool.token_program))
+            .ok_or(BountyBoardError::UnknownRewardMint)
+    }
+
+    /// The pledged and reserved totals of `reward_mint`
+    fn balances_mut(
+        &mut self,
+        reward_mint: &Pubkey,
+    ) -> Result<(&mut u64, &mut u64), BountyBoardError> {
+        if *reward_mint == Pubkey::default() {
+            return Ok((&mut self.total_pledged, &mut self.reserved));
+        }
+        self.pools
+            .iter_mut()
+            .find(|pool| pool.mint == *reward_mint)
+            .map(|pool| (&mut pool.total_pledged, &mut pool.reserved))
+            .ok_or(BountyBoardError::UnknownRewardMint)
+    }
+
+    /// Pledged funds in `reward_mint` not reserved for any task
+    pub fn available(&self, reward_mint: &Pubkey) -> u64 {
+        if *reward_mint == Pubkey::default() {
+            return self.total_pledged.saturating_sub(self.reserved);
+        }
+        self.pool(reward_mint)
+            .map_or(0, |pool| pool.total_pledged.saturating_sub(pool.reserved))
+    }
+
+    pub fn pledge(&mut self, reward_mint: &Pubkey, amount: u64) -> Result<(), BountyBoardError> {
+        let (total_pledged, _) = self.balances_mut(reward_mint)?;
+        *total_pledged += amount;
+        Ok(())
+    }
+
+    pub fn reserve(&mut self, reward_mint: &Pubkey, amount: u64) -> Result<(), BountyBoardError> {
+        if amount > self.available(reward_mint) {
+            return Err(BountyBoardError::InsufficientFunds);
+        }
+        let (_, reserved) = self.balances_mut(reward_mint)?;
+        *reserved += amount;
+        Ok(())
+    }
+
+    /// Releases a reward reservation of which `paid` left the board;
+    /// the rest becomes available again
+    pub fn release_reward(
+        &mut self,
+        reward_mint: &Pubkey,
+        reserved: u64,
+        paid: u64,
+    ) -> Result<(), BountyBoardError> {
+        let (total_pledged, total_reserved) = self.balances_mut(reward_mint)?;
+        *total_reserved = total_reserved
+            .checked_sub(reserved)
+            .ok_or(BountyBoardError::InsufficientReservation)?;
+        *total_pledged = total_pledged
+            .checked_sub(paid)
+            .ok_or(BountyBoardError::InsufficientFunds)?;
+        Ok(())
+    }
+}
+
+impl Contributor {
+    pub const TOTAL_BPS: u16 = 10_000;
+    pub const LEN: usize = 32 + 2;
+
+    /// Shares must be positive, unique per key and sum to `TOTAL_BPS`
+    pub fn validate_shares(contributors: &[Contributor]) -> Result<(), BountyBoardError> {
+        if contributors.len() > Submission::MAX_CONTRIBUTORS {
+            return Err(BountyBoardError::TooManyContributors);
+        }
+        let mut total: u32 = 0;
+        for (i, contributor) in contributors.iter().enumerate() {
+            if contributor.share_bps == 0
+                || contributors[..i].iter().any(|c| c.key == contributor.key)
+            {
+                return Err(BountyBoardError::InvalidContributorShares);
+            }
+            total += contributor.share_bps as u32;
+        }
+        if total != Self::TOTAL_BPS as u32 {
+            return Err(BountyBoardError::InvalidContributorShares);
+        }
+        Ok(())
+    }
+
+    /// This contributor's part of `amount`, rounded down
+    pub fn share_of(&self, amount: u64) -> u64 {
+        (amount as u128 * self.share_bps as u128 / Self::TOTAL_BPS as u128) as u64
+    }
+}
+
+impl Submission {
+    pub const MAX_PROOF_LEN: usize = 512;
+    pub const MAX_COMMENT_LEN: usize = 512;
+    pub const MAX_CONTRIBUTORS: usize = 8;
+    /// One payout per contributor, and the submitter's deposit refund
+    pub const MAX_PAYOUTS: usize = Self::MAX_CONTRIBUTORS + 1;
+
+    pub fn validate_proof(proof: &str) -> Result<(), BountyBoardError> {
+        if proof.len() > Self::MAX_PROOF_LEN {
+            return Err(BountyBoardError::ProofTooLong);
+        }
+        Ok(())
+    }
+
+    /// The hash a submitter commits to before revealing their proof.
+    /// Binding the submitter stops others from replaying the commitment.
+    pub fn commitment_for(
+        submitter: &Pubkey,
+        salt: &[u8; 32],
+        proof: &str,
+        proof_ref: &Option<ContentRef>,
+        encrypted_proof: &Option<EncryptedProof>,
+    ) -> std::io::Result<[u8; 32]> {
+        Ok(hashv(&[
+            submitter.as_ref(),
+            salt,
+            &proof.try_to_vec()?,
+            &proof_ref.try_to_vec()?,
+            &encrypted_proof.try_to_vec()?,
+        ])
+        .to_bytes())
+    }
+
+    pub fn validate_comment(comment: &str) -> Result<(), BountyBoardError> {
+        if comment.len() > Self::MAX_COMMENT_LEN {
+            return Err(BountyBoardError::CommentTooLong);
+        }
+        Ok(())
+    }
+
+    /// Whether the submission's account may be closed: its outcome is
+    /// settled, its deposit returned and its payouts claimed. Pending
+    /// submissions are settled once their task has finished.
+    pub fn is_finalized(&self, task_finished: bool) -> bool {
+        let settled = match self.status {
+            SubmissionStatus::Approved | SubmissionStatus::Rejected => true,
+            SubmissionStatus::Pending | SubmissionStatus::Committed => task_finished,
+            SubmissionStatus::Disputed => false,
+        };
+        settled && self.deposit == 0 && self.payouts.iter().all(|payout| payout.claimed)
+    }
+
+    /// Adds `amount` to `recipient`'s unclaimed payout
+    pub fn credit(&mut self, recipient: Pubkey, amount: u64) {
+        match self
+            .payouts
+            .iter_mut()
+            .find(|payout| payout.recipient == recipient)
+        {
+            // A claimed payout is reused, keeping one per recipient
+            Some(payout) if payout.claimed => {
+                payout.amount = amount;
+                payout.claimed = false;
+            }
+            Some(payout) => payout.amount += amount,
+            None => self.payouts.push(Payout {
+                recipient,
+                amount,
+                claimed: false,
+            }),
+        }
+    }
+
+    /// Credits a reward to the submitter, or splits it across contributors
+    /// with the last one also receiving the rounding remainder
+    pub fn credit_reward(&mut self, amount: u64) {
+        if self.contributors.is_empty() {
+            self.credit(self.submitter, amount);
+            return;
+        }
+
+        let mut remaining = amount;
+        let last = self.contributors.len() - 1;
+        for i in 0..self.contributors.len() {
+            let contributor = self.contributors[i];
+            let share = if i == last {
+                remaining
+            } else {
+                contributor.share_of(amount)
+            };
+            remaining -= share;
+            self.credit(contributor.key, share);
+        }
+    }
+
+    /// Clears the vote tally so reviewers vote afresh, on the next
+    /// milestone or a resubmitted one
+    pub fn start_review_round(&mut self) {
+        self.approvals = 0;
+        self.rejections = 0;
+        self.spam_votes = 0;
+        self.vote_commits = 0;
+        self.commit_reviewers = 0;
+        self.review_round = self.review_round.saturating_add(1);
+    }
+
+    /// A rejected deposit is slashed when most rejecting reviewers flagged spam
+    pub fn is_spam(&self) -> bool {
+        self.spam_votes as u16 * 2 > self.rejections as u16
+    }
+}
+
+impl Dispute {
+    pub const LEN: usize = AccountHeader::LEN + 1 + 32 + 32 + 8 + 8 + 1 + 1 + 1;
+}
+
+impl VoteRecord {
+    pub const LEN: usize = AccountHeader::LEN + 1 + 32 + 32 + 8 + (1 + 32) + 1;
+
+    /// The hash a reviewer commits to before revealing their vote.
+    /// Binding the reviewer stops others from replaying the commitment.
+    pub fn commitment_for(
+        reviewer: &Pubkey,
+        salt: &[u8; 32],
+        status: i8,
+        reward: &RewardChoice,
+    ) -> std::io::Result<[u8; 32]> {
+        Ok(hashv(&[
+            reviewer.as_ref(),
+            salt,
+            &status.to_le_bytes(),
+            &reward.try_to_vec()?,
+        ])
+        .to_bytes())
+    }
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 8 + 8;
+
+    pub fn is_valid(&self) -> bool {
+        self.cliff >= 0 && self.duration >= self.cliff
+    }
+}
+
+impl BoardConfig {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 1;
+}
+
+impl BoardArchive {
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+}
+
+impl TokenPool {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8;
+}
+
+impl Eligibility {
+    pub const MAX_ALLOWLIST: usize = 32;
+    pub const MAX_LEN: usize = 8 + (1 + 1) + (4 + 32 * Self::MAX_ALLOWLIST) + 1;
+}
+
+impl Payout {
+    pub const LEN: usize = 32 + 8 + 1;
+}
+
+impl ProgramConfig {
+    pub const LEN: usize = AccountHeader::LEN + 1 + 32 + 32 + 1 + (32 + 8 + 8) + 7 + (8 + 1 + 1);
+}
+
+impl Default for FeatureFlags {
     fn default() -> Self {
         Self {
-            is_initialized: false,
-            creator: Pubkey::default(),
-            name: String::new(),
-            description: String::new(),
-            img: String::new(),
-            reward_token: Pubkey::default(),
-            total_pledged: 0,
-            created_at: 0,
-            closed: false,
-            config: String::new(),
+            contests: true,
+            milestones: true,
+            vesting: true,
+            recurring_tasks: true,
+            disputes: true,
+            encrypted_proofs: true,
+            commit_reveal: true,
+        }
+    }
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        Self {
+            version: Self::VERSION,
+            join_policy: JoinPolicy::Open,
+            max_reviewers_per_task: 0,
+        }
+    }
+}
+
+impl TaskConfig {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 1 + 1 + 1 + 8 + 1;
+}
+
+impl Default for TaskConfig {
+    fn default() -> Self {
+        Self {
+            version: Self::VERSION,
+            review_policy: ReviewPolicy::AnyReviewer,
+            self_check_policy: SelfCheckPolicy::Disabled,
+            max_submissions: 0,
+            blind_voting: false,
+        }
+    }
+}
+
+impl Recurrence {
+    pub const LEN: usize = 8 + 4;
+
+    pub fn is_valid(&self) -> bool {
+        self.period > 0 && self.cycles > 0
+    }
+}
+
+impl Vesting {
+    pub const LEN: usize = AccountHeader::LEN + 1 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// The part of `total` vested at `now`
+    pub fn vested_at(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start);
+        if self.clawed_back || elapsed >= self.duration {
+            return self.total;
+        }
+        if elapsed < self.cliff {
+            return 0;
+        }
+        (self.total as u128 * elapsed as u128 / self.duration as u128) as u64
+    }
+
+    /// Vested but not yet released
+    pub fn releasable(&self, now: i64) -> u64 {
+        self.vested_at(now).saturating_sub(self.released)
+    }
+}
+
+impl Task {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_DESCRIPTION_LEN: usize = 2048;
+    pub const MAX_METADATA_LEN: usize = 512;
+    pub const MAX_REVIEWERS: usize = 16;
+    pub const MAX_REWARD_TIERS: usize = 8;
+    /// Contest places, which bounds both the prize shares and the ranking
+    pub const MAX_PRIZES: usize = 16;
+    pub const MAX_MILESTONES: usize = 16;
+
+    /// Checks the task's string fields against their byte limits
+    pub fn validate_fields(
+        name: &str,
+        description: &str,
+        metadata: &str,
+    ) -> Result<(), BountyBoardError> {
+        if name.len() > Self::MAX_NAME_LEN {
+            return Err(BountyBoardError::NameTooLong);
+        }
+        if description.len() > Self::MAX_DESCRIPTION_LEN {
+            return Err(BountyBoardError::DescriptionTooLong);
+        }
+        if metadata.len() > Self::MAX_METADATA_LEN {
+            return Err(BountyBoardError::MetadataTooLong);
+        }
+        Ok(())
+    }
+
+    /// Checks the task's lists against their length limits
+    pub fn validate_lists(&self) -> Result<(), BountyBoardError> {
+        if self.reviewers.len() > Self::MAX_REVIEWERS {
+            return Err(BountyBoardError::TooManyReviewers);
+        }
+        if self.eligibility.allowlist.len() > Eligibility::MAX_ALLOWLIST {
+            return Err(BountyBoardError::TooManyAllowlisted);
+        }
+        if self.reward_tiers.len() > Self::MAX_REWARD_TIERS {
+            return Err(BountyBoardError::TooManyRewardTiers);
+        }
+        if self.prize_shares_bps.len() > Self::MAX_PRIZES {
+            return Err(BountyBoardError::TooManyPrizes);
+        }
+        if self.milestone_shares_bps.len() > Self::MAX_MILESTONES {
+            return Err(BountyBoardError::TooManyMilestones);
+        }
+        Ok(())
+    }
+
+    /// Checks the task's eligibility rules against a board member
+    pub fn is_eligible(&self, member: &Member) -> bool {
+        let rules = &self.eligibility;
+        if member.reputation < rules.min_reputation {
+            return false;
+        }
+        if let Some(role) = rules.required_role {
+            if member.role != role {
+                return false;
+            }
         }
+        if !rules.allowlist.is_empty() && !rules.allowlist.contains(&member.user) {
+            return false;
+        }
+        if rules.joined_before_task && member.joined_at >= self.created_at {
+            return false;
+        }
+        true
+    }
+
+    /// The total reward the board must reserve for this task
+    pub fn total_reward(&self) -> Option<u64> {
+        match self.kind {
+            TaskKind::Standard | TaskKind::Milestone => {
+                self.reward_amount.checked_mul(self.max_completions)
+            }
+            TaskKind::Contest => Some(self.reward_amount),
+        }
+    }
+
+    /// Prize for the 0-based `place` of a contest, rounded down
+    pub fn prize_for(&self, place: usize) -> u64 {
+        self.prize_shares_bps.get(place).map_or(0, |bps| {
+            (self.reward_amount as u128 * *bps as u128 / Contributor::TOTAL_BPS as u128) as u64
+        })
+    }
+
+    /// Reward for the 0-based milestone `index`, rounded down. The final
+    /// milestone also receives the rounding remainder.
+    pub fn milestone_reward(&self, index: usize) -> u64 {
+        let share = |bps: &u16| {
+            (self.reward_amount as u128 * *bps as u128 / Contributor::TOTAL_BPS as u128) as u64
+        };
+        if index + 1 == self.milestone_shares_bps.len() {
+            let earlier: u64 = self.milestone_shares_bps[..index].iter().map(share).sum();
+            return self.reward_amount - earlier;
+        }
+        self.milestone_shares_bps.get(index).map_or(0, share)
+    }
+
+    pub fn is_final_milestone(&self, index: u8) -> bool {
+        index as usize + 1 >= self.milestone_shares_bps.len()
+    }
+
+    /// Whether the task takes no more submissions or reviews
+    pub fn is_finished(&self) -> bool {
+        self.expired || self.cancelled || (self.completed && !self.has_next_cycle())
+    }
+
+    /// Whether another cycle of a recurring task remains to be opened
+    pub fn has_next_cycle(&self) -> bool {
+        self.recurrence
+            .is_some_and(|recurrence| self.cycle + 1 < recurrence.cycles)
+    }
+
+    /// Counts an approved submission towards the task's completions
+    pub fn record_completion(&mut self) {
+        self.num_completions += 1;
+        if self.num_completions >= self.max_completions {
+            self.completed = true;
+        }
+    }
+
+    /// Resolves a reviewer's reward choice against the task's cap and tiers
+    pub fn reward_for(&self, choice: RewardChoice) -> Result<u64, BountyBoardError> {
+        match choice {
+            RewardChoice::Full => Ok(self.reward_amount),
+            RewardChoice::Custom(amount) if amount <= self.reward_amount => Ok(amount),
+            RewardChoice::Custom(_) => Err(BountyBoardError::RewardExceedsCap),
+            RewardChoice::Tier(tier) => self
+                .reward_tiers
+                .get(tier as usize)
+                .copied()
+                .ok_or(BountyBoardError::InvalidRewardTier),
+        }
+    }
+
+    pub fn is_reviewer(&self, key: &Pubkey) -> bool {
+        self.reviewers.contains(key)
+    }
+
+    /// Whether `approvals` can still reach the approval threshold
+    /// once `rejections` reviewers have voted against
+    pub fn threshold_reachable(&self, rejections: u8) -> bool {
+        let remaining = self.reviewers.len().saturating_sub(rejections as usize);
+        remaining >= self.approval_threshold as usize
     }
 }
 
 impl Default for Task {
     fn default() -> Self {
         Self {
+            header: Self::header(),
             id: 0,
+            board: Pubkey::default(),
             name: String::new(),
             creator: Pubkey::default(),
             description: String::new(),
@@ -91,8 +1365,29 @@ impl Default for Task {
             reward_amount: 0,
             created_at: 0,
             cancelled: false,
-            config: String::new(),
-            allow_self_check: false,
+            metadata: String::new(),
+            config: TaskConfig::default(),
+            eligibility: Eligibility::default(),
+            approval_threshold: 1,
+            submission_deposit: 0,
+            deposit_kind: DepositKind::Lamports,
+            reward_tiers: Vec::new(),
+            kind: TaskKind::Standard,
+            prize_shares_bps: Vec::new(),
+            ranking: Vec::new(),
+            reward_mint: Pubkey::default(),
+            vesting: None,
+            milestone_shares_bps: Vec::new(),
+            recurrence: None,
+            cycle: 0,
+            review_grace_period: 0,
+            expiry_policy: ExpiryPolicy::RejectPending,
+            expired: false,
+            reserved: 0,
+            num_submissions: 0,
+            description_ref: None,
+            open_submissions: 0,
+            submissions_tracked: true,
         }
     }
 }
@@ -100,11 +1395,613 @@ impl Default for Task {
 impl Default for Submission {
     fn default() -> Self {
         Self {
+            header: Self::header(),
+            task: Pubkey::default(),
             submitter: Pubkey::default(),
             proof: String::new(),
             status: SubmissionStatus::Pending,
             submitted_at: 0,
             review_comment: String::new(),
+            approvals: 0,
+            rejections: 0,
+            spam_votes: 0,
+            deposit: 0,
+            approved_amount: 0,
+            contributors: Vec::new(),
+            payouts: Vec::new(),
+            milestone: 0,
+            review_round: 0,
+            proof_ref: None,
+            encrypted_proof: None,
+            commitment: None,
+            revealed_at: 0,
+            vote_commits: 0,
+            commit_reviewers: 0,
+            cycle: 0,
+        }
+    }
+}
+
+impl Default for Member {
+    fn default() -> Self {
+        Self {
+            header: Self::header(),
+            is_initialized: false,
+            board: Pubkey::default(),
+            user: Pubkey::default(),
+            joined_at: 0,
+            reputation: 0,
+            role: MemberRole::Member,
+            payout_address: Pubkey::default(),
+        }
+    }
+}
+
+impl WrappedKey {
+    pub const LEN: usize = 32 + 48;
+}
+
+impl EncryptedProof {
+    pub const MAX_CIPHERTEXT_LEN: usize = 1024;
+    pub const MAX_LEN: usize = (4 + Self::MAX_CIPHERTEXT_LEN)
+        + 12
+        + 32
+        + (4 + WrappedKey::LEN * Task::MAX_REVIEWERS);
+
+    /// Checks the proof is wrapped exactly once for every reviewer
+    pub fn validate(&self, reviewers: &[Pubkey]) -> Result<(), BountyBoardError> {
+        if self.ciphertext.len() > Self::MAX_CIPHERTEXT_LEN {
+            return Err(BountyBoardError::ProofTooLong);
+        }
+        let wrapped_once = |reviewer: &Pubkey| {
+            self.wrapped_keys
+                .iter()
+                .filter(|wrapped| wrapped.reviewer == *reviewer)
+                .count()
+                == 1
+        };
+        if self.wrapped_keys.len() != reviewers.len() || !reviewers.iter().all(wrapped_once) {
+            return Err(BountyBoardError::ReviewerKeysMismatch);
         }
+        Ok(())
     }
-}
\ No newline at end of file
+
+    pub fn key_for(&self, reviewer: &Pubkey) -> Option<&WrappedKey> {
+        self.wrapped_keys
+            .iter()
+            .find(|wrapped| wrapped.reviewer == *reviewer)
+    }
+}
+
+impl ContentRef {
+    pub const MAX_URI_LEN: usize = 256;
+    pub const MAX_LEN: usize = (4 + Self::MAX_URI_LEN) + 32;
+
+    /// Pins `content` published at `uri`
+    pub fn new(uri: String, content: &[u8]) -> Self {
+        Self {
+            uri,
+            hash: hash(content).to_bytes(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), BountyBoardError> {
+        if self.uri.len() > Self::MAX_URI_LEN {
+            return Err(BountyBoardError::ContentUriTooLong);
+        }
+        if !is_valid_url(&self.uri) {
+            return Err(BountyBoardError::InvalidContentUri);
+        }
+        Ok(())
+    }
+
+    /// Whether `content` fetched from `uri` matches the pinned hash
+    pub fn verify(&self, content: &[u8]) -> bool {
+        hash(content).to_bytes() == self.hash
+    }
+}
+
+/// Whether `url` is an `https`, `ipfs` or `ar` URL without whitespace
+/// or control characters
+pub fn is_valid_url(url: &str) -> bool {
+    let rest = ["https://", "ipfs://", "ar://"]
+        .iter()
+        .find_map(|scheme| url.strip_prefix(scheme));
+    match rest {
+        Some(rest) => {
+            !rest.is_empty() && !rest.chars().any(|c| c.is_whitespace() || c.is_control())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legacy_task() -> legacy::Task {
+        legacy::Task {
+            id: 7,
+            name: "Task".to_string(),
+            creator: Pubkey::new_unique(),
+            description: "Description".to_string(),
+            deadline: 1_000,
+            max_completions: 2,
+            num_completions: 1,
+            reviewers: vec![Pubkey::new_unique()],
+            completed: false,
+            reward_amount: 500,
+            created_at: 100,
+            cancelled: false,
+            config: "{}".to_string(),
+            allow_self_check: true,
+        }
+    }
+
+    #[test]
+    fn load_ignores_trailing_bytes() {
+        let mut task = Task::default();
+        task.name = "Task".to_string();
+        let mut data = task.try_to_vec().unwrap();
+        data.resize(Task::MAX_LEN, 0);
+
+        let loaded = Task::load(&data).unwrap();
+        assert_eq!(loaded.header, Task::header());
+        assert_eq!(loaded.name, "Task");
+    }
+
+    #[test]
+    fn load_rejects_other_kinds_and_versions() {
+        let data = Task::default().try_to_vec().unwrap();
+        assert_eq!(
+            Submission::load(&data).unwrap_err(),
+            BountyBoardError::InvalidAccountKind.into()
+        );
+
+        let mut data = data;
+        data[AccountHeader::LEN - 1] = Task::VERSION + 1;
+        assert_eq!(
+            Task::load(&data).unwrap_err(),
+            BountyBoardError::AccountNotMigrated.into()
+        );
+    }
+
+    #[test]
+    fn load_upgrades_headerless_accounts() {
+        assert_eq!(
+            Task::load(&[0; 64]).unwrap_err(),
+            ProgramError::UninitializedAccount
+        );
+
+        let mut data = legacy_task().try_to_vec().unwrap();
+        assert_eq!(AccountHeader::read(&data), None);
+        // Legacy tasks only load as part of a board
+        assert_eq!(
+            Task::load(&data).unwrap_err(),
+            BountyBoardError::AccountNotMigrated.into()
+        );
+
+        data.resize(data.len() + 16, 0);
+        let board = Pubkey::new_unique();
+        let task = Task::load_in(&data, &board).unwrap();
+        assert_eq!(task.header, Task::header());
+        assert_eq!(task.board, board);
+        assert_eq!(task.name, "Task");
+        assert!(!task.submissions_tracked);
+
+        // The converted task outgrows the legacy account until migrated
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account_info =
+            AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(
+            task.save(&account_info).unwrap_err(),
+            BountyBoardError::AccountTooSmall.into()
+        );
+    }
+
+    #[test]
+    fn board_from_legacy() {
+        let legacy = legacy::Board {
+            is_initialized: true,
+            creator: Pubkey::new_unique(),
+            name: "Board".to_string(),
+            description: "Description".to_string(),
+            img: "https://example.com/board.png".to_string(),
+            reward_token: Pubkey::new_unique(),
+            total_pledged: 1_000,
+            created_at: 100,
+            closed: false,
+            config: "{}".to_string(),
+        };
+
+        let board = Board::from_legacy(legacy.clone());
+        assert_eq!(board.header, Board::header());
+        assert_eq!(board.creator, legacy.creator);
+        assert_eq!(board.reward_token, legacy.reward_token);
+        assert_eq!(board.token_program, spl_token::id());
+        assert_eq!(board.metadata, legacy.config);
+        // Legacy pledges are not carried over
+        assert_eq!(board.total_pledged, 0);
+
+        let data = board.try_to_vec().unwrap();
+        assert!(data.len() <= Board::MAX_LEN);
+        assert_eq!(Board::load(&data).unwrap().name, "Board");
+
+        // Boards belong to nothing, so they load without migrating
+        let data = legacy.try_to_vec().unwrap();
+        assert_eq!(Board::load(&data).unwrap().creator, legacy.creator);
+    }
+
+    #[test]
+    fn task_from_legacy() {
+        let legacy = legacy_task();
+        let board = Pubkey::new_unique();
+
+        let task = Task::from_legacy(legacy.clone(), &board);
+        assert_eq!(task.header, Task::header());
+        assert_eq!(task.board, board);
+        assert_eq!(task.reviewers, legacy.reviewers);
+        assert_eq!(task.num_completions, 1);
+        assert_eq!(task.reserved, 0);
+        assert_eq!(task.config.self_check_policy, SelfCheckPolicy::Enabled);
+        assert!(!task.submissions_tracked);
+    }
+
+    #[test]
+    fn submission_from_legacy() {
+        let legacy = legacy::Submission {
+            submitter: Pubkey::new_unique(),
+            proof: "https://example.com/proof".to_string(),
+            status: legacy::SubmissionStatus::Approved,
+            submitted_at: 200,
+            review_comment: "Good".to_string(),
+        };
+        let task = Pubkey::new_unique();
+
+        let submission = Submission::from_legacy(legacy.clone(), &task);
+        assert_eq!(submission.header, Submission::header());
+        assert_eq!(submission.task, task);
+        assert_eq!(submission.submitter, legacy.submitter);
+        assert_eq!(submission.status, SubmissionStatus::Approved);
+        assert!(submission.payouts.is_empty());
+    }
+
+    #[test]
+    fn eligibility_checks_every_rule() {
+        let mut member = Member::default();
+        member.user = Pubkey::new_unique();
+        member.reputation = 10;
+        member.role = MemberRole::Contributor;
+        member.joined_at = 100;
+
+        let mut task = Task::default();
+        task.created_at = 200;
+        assert!(task.is_eligible(&member));
+
+        task.eligibility.min_reputation = 11;
+        assert!(!task.is_eligible(&member));
+        task.eligibility.min_reputation = 10;
+        assert!(task.is_eligible(&member));
+
+        task.eligibility.required_role = Some(MemberRole::Moderator);
+        assert!(!task.is_eligible(&member));
+        task.eligibility.required_role = Some(MemberRole::Contributor);
+        assert!(task.is_eligible(&member));
+
+        task.eligibility.allowlist = vec![Pubkey::new_unique()];
+        assert!(!task.is_eligible(&member));
+        task.eligibility.allowlist.push(member.user);
+        assert!(task.is_eligible(&member));
+    }
+
+    #[test]
+    fn eligibility_requires_joining_strictly_before_task() {
+        let mut task = Task::default();
+        task.created_at = 200;
+        task.eligibility.joined_before_task = true;
+        let mut member = Member::default();
+
+        member.joined_at = 199;
+        assert!(task.is_eligible(&member));
+        member.joined_at = 200;
+        assert!(!task.is_eligible(&member));
+        member.joined_at = 201;
+        assert!(!task.is_eligible(&member));
+
+        // Members converted from the original program count as joined first
+        member.joined_at = 0;
+        assert!(task.is_eligible(&member));
+    }
+
+    #[test]
+    fn vesting_releases_linearly_after_cliff() {
+        let mut vesting = Vesting::default();
+        vesting.total = 1_000;
+        vesting.start = 100;
+        vesting.cliff = 10;
+        vesting.duration = 40;
+
+        assert_eq!(vesting.vested_at(50), 0);
+        assert_eq!(vesting.vested_at(109), 0);
+        assert_eq!(vesting.vested_at(110), 250);
+        assert_eq!(vesting.vested_at(113), 325);
+        assert_eq!(vesting.vested_at(140), 1_000);
+        assert_eq!(vesting.vested_at(i64::MAX), 1_000);
+
+        vesting.released = 250;
+        assert_eq!(vesting.releasable(120), 250);
+    }
+
+    #[test]
+    fn clawed_back_vesting_is_fully_vested() {
+        let mut vesting = Vesting::default();
+        vesting.total = 400;
+        vesting.start = 100;
+        vesting.duration = 40;
+        vesting.clawed_back = true;
+
+        assert_eq!(vesting.vested_at(100), 400);
+    }
+
+    #[test]
+    fn final_milestone_receives_remainder() {
+        let mut task = Task::default();
+        task.kind = TaskKind::Milestone;
+        task.reward_amount = 100;
+        task.milestone_shares_bps = vec![3_333, 3_333, 3_334];
+
+        assert_eq!(task.milestone_reward(0), 33);
+        assert_eq!(task.milestone_reward(1), 33);
+        assert_eq!(task.milestone_reward(2), 34);
+        assert_eq!(task.milestone_reward(3), 0);
+        assert!(!task.is_final_milestone(1));
+        assert!(task.is_final_milestone(2));
+    }
+
+    #[test]
+    fn contest_prizes_round_down_by_place() {
+        let mut task = Task::default();
+        task.kind = TaskKind::Contest;
+        task.reward_amount = 1_001;
+        task.max_completions = 5;
+        task.prize_shares_bps = vec![5_000, 3_000, 2_000];
+
+        assert_eq!(task.total_reward(), Some(1_001));
+        assert_eq!(task.prize_for(0), 500);
+        assert_eq!(task.prize_for(1), 300);
+        assert_eq!(task.prize_for(2), 200);
+        // Places beyond the shares win nothing; the dust stays unawarded
+        assert_eq!(task.prize_for(3), 0);
+
+        task.reward_amount = u64::MAX;
+        task.prize_shares_bps = vec![Contributor::TOTAL_BPS];
+        assert_eq!(task.prize_for(0), u64::MAX);
+    }
+
+    #[test]
+    fn reward_goes_to_submitter_without_contributors() {
+        let mut submission = Submission::default();
+        submission.submitter = Pubkey::new_unique();
+
+        submission.credit_reward(100);
+        submission.credit_reward(50);
+        assert_eq!(submission.payouts.len(), 1);
+        assert_eq!(submission.payouts[0].recipient, submission.submitter);
+        assert_eq!(submission.payouts[0].amount, 150);
+    }
+
+    #[test]
+    fn reward_splits_across_contributors() {
+        let first = Pubkey::new_unique();
+        let last = Pubkey::new_unique();
+        let mut submission = Submission::default();
+        submission.submitter = first;
+        submission.contributors = vec![
+            Contributor {
+                key: first,
+                share_bps: 3_333,
+            },
+            Contributor {
+                key: last,
+                share_bps: 6_667,
+            },
+        ];
+
+        // The last contributor receives the rounding remainder
+        submission.credit_reward(100);
+        assert_eq!(submission.payouts.len(), 2);
+        assert_eq!(submission.payouts[0].recipient, first);
+        assert_eq!(submission.payouts[0].amount, 33);
+        assert_eq!(submission.payouts[1].recipient, last);
+        assert_eq!(submission.payouts[1].amount, 67);
+    }
+
+    #[test]
+    fn credit_reuses_claimed_payouts() {
+        let recipient = Pubkey::new_unique();
+        let mut submission = Submission::default();
+        submission.credit(recipient, 100);
+        submission.payouts[0].claimed = true;
+
+        submission.credit(recipient, 30);
+        assert_eq!(submission.payouts.len(), 1);
+        assert_eq!(submission.payouts[0].amount, 30);
+        assert!(!submission.payouts[0].claimed);
+    }
+
+    #[test]
+    fn valid_urls() {
+        assert!(is_valid_url("https://example.com/proof"));
+        assert!(is_valid_url("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"));
+        assert!(is_valid_url("ar://abc"));
+        assert!(!is_valid_url("http://example.com"));
+        assert!(!is_valid_url("https://"));
+        assert!(!is_valid_url("https://example.com/a b"));
+        assert!(!is_valid_url("https://example.com/\n"));
+        assert!(!is_valid_url("javascript:alert(1)"));
+    }
+
+    #[test]
+    fn content_ref_detects_tampered_content() {
+        let content = b"Full task description";
+        let content_ref = ContentRef::new("ipfs://bafy".to_string(), content);
+
+        assert!(content_ref.verify(content));
+        assert!(!content_ref.verify(b"Full task description!"));
+        assert!(!content_ref.verify(b""));
+    }
+
+    #[test]
+    fn content_ref_validates_uri() {
+        assert!(ContentRef::new("ar://abc".to_string(), b"").validate().is_ok());
+        assert!(matches!(
+            ContentRef::new("http://example.com".to_string(), b"").validate(),
+            Err(BountyBoardError::InvalidContentUri)
+        ));
+        let uri = format!("https://{}", "a".repeat(ContentRef::MAX_URI_LEN - 8));
+        assert!(ContentRef::new(uri.clone(), b"").validate().is_ok());
+        assert!(matches!(
+            ContentRef::new(uri + "a", b"").validate(),
+            Err(BountyBoardError::ContentUriTooLong)
+        ));
+    }
+
+    #[test]
+    fn task_lists_are_bounded() {
+        let mut task = Task::default();
+        task.reviewers = vec![Pubkey::new_unique(); Task::MAX_REVIEWERS];
+        task.milestone_shares_bps = vec![1; Task::MAX_MILESTONES];
+        assert!(task.validate_lists().is_ok());
+
+        task.reviewers.push(Pubkey::new_unique());
+        assert!(matches!(
+            task.validate_lists(),
+            Err(BountyBoardError::TooManyReviewers)
+        ));
+        task.reviewers.pop();
+        task.milestone_shares_bps.push(1);
+        assert!(matches!(
+            task.validate_lists(),
+            Err(BountyBoardError::TooManyMilestones)
+        ));
+    }
+
+    #[test]
+    fn largest_submission_fits_max_len() {
+        let mut submission = Submission::default();
+        submission.proof = "p".repeat(Submission::MAX_PROOF_LEN);
+        submission.review_comment = "c".repeat(Submission::MAX_COMMENT_LEN);
+        submission.contributors = vec![
+            Contributor {
+                key: Pubkey::new_unique(),
+                share_bps: 1,
+            };
+            Submission::MAX_CONTRIBUTORS
+        ];
+        submission.payouts = vec![
+            Payout {
+                recipient: Pubkey::new_unique(),
+                amount: 1,
+                claimed: false,
+            };
+            Submission::MAX_PAYOUTS
+        ];
+        submission.proof_ref = Some(ContentRef {
+            uri: "u".repeat(ContentRef::MAX_URI_LEN),
+            hash: [0; 32],
+        });
+        submission.encrypted_proof = Some(EncryptedProof {
+            ciphertext: vec![0; EncryptedProof::MAX_CIPHERTEXT_LEN],
+            nonce: [0; 12],
+            ephemeral_key: [0; 32],
+            wrapped_keys: vec![
+                WrappedKey {
+                    reviewer: Pubkey::new_unique(),
+                    key: [0; 48],
+                };
+                Task::MAX_REVIEWERS
+            ],
+        });
+        submission.commitment = Some([0; 32]);
+
+        assert_eq!(submission.try_to_vec().unwrap().len(), Submission::MAX_LEN);
+    }
+
+    #[test]
+    fn save_rejects_small_accounts() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![0; Dispute::LEN - 1];
+        let account_info =
+            AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+
+        assert_eq!(
+            Dispute::default().save(&account_info).unwrap_err(),
+            BountyBoardError::AccountTooSmall.into()
+        );
+    }
+
+    #[test]
+    fn proof_commitment_binds_submitter_salt_and_proof() {
+        let submitter = Pubkey::new_unique();
+        let salt = [7; 32];
+        let commit = |submitter: &Pubkey, salt: &[u8; 32], proof: &str| {
+            Submission::commitment_for(submitter, salt, proof, &None, &None).unwrap()
+        };
+
+        let commitment = commit(&submitter, &salt, "proof");
+        assert_eq!(commitment, commit(&submitter, &salt, "proof"));
+        assert_ne!(commitment, commit(&Pubkey::new_unique(), &salt, "proof"));
+        assert_ne!(commitment, commit(&submitter, &[8; 32], "proof"));
+        assert_ne!(commitment, commit(&submitter, &salt, "other proof"));
+
+        let proof_ref = Some(ContentRef::new("https://example.com".to_string(), b"proof"));
+        let with_ref =
+            Submission::commitment_for(&submitter, &salt, "proof", &proof_ref, &None).unwrap();
+        assert_ne!(commitment, with_ref);
+    }
+
+    #[test]
+    fn release_reward_checks_balances() {
+        let mut board = Board::default();
+        board.pledge(&Pubkey::default(), 1_000).unwrap();
+        board.reserve(&Pubkey::default(), 400).unwrap();
+        assert!(matches!(
+            board.reserve(&Pubkey::default(), 601),
+            Err(BountyBoardError::InsufficientFunds)
+        ));
+
+        board.release_reward(&Pubkey::default(), 400, 300).unwrap();
+        assert_eq!(board.reserved, 0);
+        assert_eq!(board.total_pledged, 700);
+        assert_eq!(board.available(&Pubkey::default()), 700);
+
+        assert!(matches!(
+            board.release_reward(&Pubkey::default(), 1, 0),
+            Err(BountyBoardError::InsufficientReservation)
+        ));
+        assert!(matches!(
+            board.release_reward(&Pubkey::new_unique(), 0, 0),
+            Err(BountyBoardError::UnknownRewardMint)
+        ));
+    }
+
+    #[test]
+    fn vote_commitment_binds_reviewer_and_vote() {
+        let reviewer = Pubkey::new_unique();
+        let salt = [7; 32];
+        let commitment =
+            VoteRecord::commitment_for(&reviewer, &salt, 1, &RewardChoice::Full).unwrap();
+
+        let commit = |reviewer: &Pubkey, status: i8, reward: &RewardChoice| {
+            VoteRecord::commitment_for(reviewer, &salt, status, reward).unwrap()
+        };
+        assert_eq!(commitment, commit(&reviewer, 1, &RewardChoice::Full));
+        assert_ne!(commitment, commit(&Pubkey::new_unique(), 1, &RewardChoice::Full));
+        assert_ne!(commitment, commit(&reviewer, -1, &RewardChoice::Full));
+        assert_ne!(commitment, commit(&reviewer, 1, &RewardChoice::Tier(0)));
+    }
+}
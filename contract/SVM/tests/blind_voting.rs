@@ -0,0 +1,172 @@
+//! Commit-reveal voting on submissions of blind voting tasks
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{
+        Board, DepositKind, Eligibility, ExpiryPolicy, RewardChoice, Submission, SubmissionStatus,
+        Task, TaskConfig, TaskKind, Versioned, VoteRecord,
+    },
+};
+use common::{custom, find_pda, process, process_with, submission, Account, SolBoard, NOW, RENT};
+use solana_program::{pubkey::Pubkey, system_program};
+
+fn blind_vote_accounts(board: &SolBoard, reviewers: usize, salt: &[u8; 32]) -> Vec<Account> {
+    let reviewer = Pubkey::new_unique();
+    let task_key = Pubkey::new_unique();
+    let mut task = board.task();
+    task.kind = TaskKind::Standard;
+    task.config.blind_voting = true;
+    task.approval_threshold = 1;
+    task.reviewers = vec![reviewer];
+    task.reviewers.resize_with(reviewers, Pubkey::new_unique);
+    task.deposit_kind = DepositKind::Lamports;
+    task.submission_deposit = 50;
+
+    let submission_key = Pubkey::new_unique();
+    let mut pending = submission(task_key, SubmissionStatus::Pending);
+    pending.deposit = 50;
+    pending.vote_commits = 1;
+    pending.commit_reviewers = reviewers as u8;
+
+    let mut vote = VoteRecord::default();
+    vote.header = VoteRecord::header();
+    vote.is_initialized = true;
+    vote.submission = submission_key;
+    vote.reviewer = reviewer;
+    vote.commitment =
+        Some(VoteRecord::commitment_for(&reviewer, salt, -2, &RewardChoice::Full).unwrap());
+    let vote_key = find_pda(&[b"vote", submission_key.as_ref(), reviewer.as_ref()]);
+
+    vec![
+        Account::wallet(reviewer, true),
+        board.account(),
+        Account::program(task_key, &task, RENT),
+        Account::program(submission_key, &pending, RENT + 50),
+        Account::program(vote_key, &vote, RENT),
+        Account::wallet(system_program::id(), false),
+        Account::wallet(pending.submitter, false),
+        board.vault_account(),
+    ]
+}
+
+fn reveal_spam(salt: [u8; 32]) -> BountyBoardInstruction {
+    BountyBoardInstruction::RevealVote {
+        status: -2,
+        review_comment: "Spam".to_string(),
+        reward: RewardChoice::Full,
+        salt,
+    }
+}
+
+#[test]
+fn revealed_spam_vote_slashes_deposit_to_sol_vault() {
+    let board = SolBoard::new(1_000, 0);
+    let salt = [3; 32];
+    let mut accounts = blind_vote_accounts(&board, 1, &salt);
+    let order = [0, 1, 2, 3, 4, 5, 6, 7, 5, 5, 5, 5];
+
+    assert_eq!(
+        process_with(&mut accounts, &order, reveal_spam([4; 32])),
+        Err(custom(BountyBoardError::CommitmentMismatch))
+    );
+
+    process_with(&mut accounts, &order, reveal_spam(salt)).unwrap();
+    assert!(accounts[4].load::<VoteRecord>().revealed);
+    let rejected = accounts[3].load::<Submission>();
+    assert_eq!(rejected.status, SubmissionStatus::Rejected);
+    assert_eq!(rejected.deposit, 0);
+    assert_eq!(accounts[3].lamports, RENT);
+    assert_eq!(accounts[7].lamports, RENT + 1_050);
+    assert_eq!(accounts[1].load::<Board>().total_pledged, 1_050);
+}
+
+#[test]
+fn blind_votes_wait_for_every_reviewer() {
+    let board = SolBoard::new(1_000, 0);
+    let salt = [3; 32];
+    let mut accounts = blind_vote_accounts(&board, 2, &salt);
+
+    assert_eq!(
+        process(&mut accounts, reveal_spam(salt)),
+        Err(custom(BountyBoardError::RevealNotOpen))
+    );
+    assert_eq!(
+        process(
+            &mut accounts,
+            BountyBoardInstruction::ReviewSubmission {
+                status: -2,
+                review_comment: "Spam".to_string(),
+                reward: RewardChoice::Full,
+            },
+        ),
+        Err(custom(BountyBoardError::BlindVotingRequired))
+    );
+}
+
+#[test]
+fn reviewers_added_after_commits_do_not_hold_back_reveals() {
+    let board = SolBoard::new(1_000, 0);
+    let salt = [3; 32];
+    let mut accounts = blind_vote_accounts(&board, 1, &salt);
+    let mut task = accounts[2].load::<Task>();
+    task.reviewers.push(Pubkey::new_unique());
+    accounts[2] = Account::program(accounts[2].key, &task, RENT);
+    let order = [0, 1, 2, 3, 4, 5, 6, 7, 5, 5, 5, 5];
+
+    process_with(&mut accounts, &order, reveal_spam(salt)).unwrap();
+    assert!(accounts[4].load::<VoteRecord>().revealed);
+    assert_eq!(accounts[3].load::<Submission>().spam_votes, 1);
+}
+
+fn create_blind_task(deadline: i64) -> BountyBoardInstruction {
+    let mut config = TaskConfig::default();
+    config.blind_voting = true;
+    BountyBoardInstruction::CreateTask {
+        name: "Task".to_string(),
+        description: "Description".to_string(),
+        deadline,
+        max_completions: 1,
+        reward_amount: 300,
+        metadata: String::new(),
+        config,
+        eligibility: Eligibility::default(),
+        approval_threshold: 1,
+        submission_deposit: 0,
+        deposit_kind: DepositKind::Lamports,
+        reward_tiers: Vec::new(),
+        kind: TaskKind::Standard,
+        prize_shares_bps: Vec::new(),
+        reward_mint: Pubkey::default(),
+        vesting: None,
+        milestone_shares_bps: Vec::new(),
+        recurrence: None,
+        review_grace_period: 0,
+        expiry_policy: ExpiryPolicy::RejectPending,
+        description_ref: None,
+    }
+}
+
+#[test]
+fn blind_voting_tasks_need_a_deadline() {
+    let board = SolBoard::new(1_000, 0);
+    let mut accounts = vec![
+        Account::wallet(board.board.creator, true),
+        board.account(),
+        Account::empty::<Task>(Pubkey::new_unique()),
+    ];
+
+    assert_eq!(
+        process(&mut accounts, create_blind_task(0)),
+        Err(custom(BountyBoardError::DeadlineRequired))
+    );
+
+    process(&mut accounts, create_blind_task(NOW + 100)).unwrap();
+    let task = accounts[2].load::<Task>();
+    assert!(task.config.blind_voting);
+    assert_eq!(accounts[1].load::<Board>().reserved, 300);
+}
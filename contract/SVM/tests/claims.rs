@@ -0,0 +1,64 @@
+//! Claims of rewards from the vaults of SOL boards
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Submission, SubmissionStatus},
+};
+use common::{custom, process_with, submission, Account, SolBoard, RENT};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+#[test]
+fn claim_reward_pays_from_sol_vault() {
+    let board = SolBoard::new(1_000, 0);
+    let task_key = Pubkey::new_unique();
+    let mut approved = submission(task_key, SubmissionStatus::Approved);
+    approved.credit_reward(300);
+    let payee = approved.submitter;
+
+    let mut accounts = vec![
+        Account::wallet(payee, true),
+        board.account(),
+        Account::program(task_key, &board.task(), RENT),
+        Account::program(Pubkey::new_unique(), &approved, RENT),
+        board.vault_account(),
+    ];
+    let order = [0, 1, 2, 3, 0, 4];
+
+    process_with(&mut accounts, &order, BountyBoardInstruction::ClaimReward).unwrap();
+    assert_eq!(accounts[0].lamports, RENT + 300);
+    assert_eq!(accounts[4].lamports, RENT + 700);
+    assert!(accounts[3].load::<Submission>().payouts[0].claimed);
+
+    // Nothing is left to claim
+    assert_eq!(
+        process_with(&mut accounts, &order, BountyBoardInstruction::ClaimReward),
+        Err(custom(BountyBoardError::NothingToClaim))
+    );
+}
+
+#[test]
+fn claim_reward_rejects_other_vaults() {
+    let board = SolBoard::new(1_000, 0);
+    let task_key = Pubkey::new_unique();
+    let mut approved = submission(task_key, SubmissionStatus::Approved);
+    approved.credit_reward(300);
+    let payee = approved.submitter;
+
+    let mut accounts = vec![
+        Account::wallet(payee, true),
+        board.account(),
+        Account::program(task_key, &board.task(), RENT),
+        Account::program(Pubkey::new_unique(), &approved, RENT),
+        Account::wallet(Pubkey::new_unique(), false),
+    ];
+
+    assert_eq!(
+        process_with(&mut accounts, &[0, 1, 2, 3, 0, 4], BountyBoardInstruction::ClaimReward),
+        Err(ProgramError::InvalidAccountData)
+    );
+}
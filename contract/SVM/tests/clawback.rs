@@ -0,0 +1,82 @@
+//! Clawbacks of vesting rewards after an upheld clawback dispute
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Board, Dispute, Submission, SubmissionStatus, Versioned, Vesting, VestingSchedule},
+};
+use common::{custom, find_pda, process, process_with, submission, Account, SolBoard, NOW, RENT};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn clawback_requires_upheld_dispute() {
+    let board = SolBoard::new(1_000, 0);
+    let arbiter = Pubkey::new_unique();
+    let mut board_state = board.board.clone();
+    board_state.arbiters = vec![arbiter];
+
+    let task_key = Pubkey::new_unique();
+    let mut task = board.task();
+    task.vesting = Some(VestingSchedule {
+        cliff: 0,
+        duration: 100,
+    });
+    let submission_key = Pubkey::new_unique();
+    let approved = submission(task_key, SubmissionStatus::Approved);
+    let mut vesting = Vesting::default();
+    vesting.header = Vesting::header();
+    vesting.is_initialized = true;
+    vesting.submission = submission_key;
+    vesting.total = 1_000;
+    vesting.start = NOW - 50;
+    vesting.duration = 100;
+
+    let mut dispute = Dispute::default();
+    dispute.header = Dispute::header();
+    dispute.is_initialized = true;
+    dispute.submission = submission_key;
+    dispute.submitter = approved.submitter;
+    dispute.clawback = true;
+    let dispute_key = find_pda(&[b"clawback", submission_key.as_ref()]);
+
+    let mut accounts = vec![
+        Account::wallet(arbiter, true),
+        Account::program(board.key, &board_state, RENT),
+        Account::program(task_key, &task, RENT),
+        Account::program(submission_key, &approved, RENT),
+        Account::program(Pubkey::new_unique(), &vesting, RENT),
+        Account::program(dispute_key, &dispute, RENT),
+    ];
+    let clawback = || BountyBoardInstruction::ClawbackVesting {
+        comment: "Clawed back".to_string(),
+    };
+
+    // The dispute has not been ruled on
+    assert_eq!(
+        process(&mut accounts, clawback()),
+        Err(custom(BountyBoardError::ClawbackNotUpheld))
+    );
+
+    // An arbiter overturns the approval
+    process_with(
+        &mut accounts,
+        &[0, 1, 2, 3, 5],
+        BountyBoardInstruction::ResolveDispute {
+            overturn: true,
+            comment: "Overturned".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(accounts[3].load::<Submission>().status, SubmissionStatus::Approved);
+
+    process(&mut accounts, clawback()).unwrap();
+    let clawed_back = accounts[4].load::<Vesting>();
+    assert!(clawed_back.clawed_back);
+    assert_eq!(clawed_back.total, 500);
+    assert_eq!(accounts[3].load::<Submission>().payouts[0].amount, 500);
+    assert_eq!(accounts[1].load::<Board>().total_pledged, 1_500);
+}
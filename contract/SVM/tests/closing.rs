@@ -0,0 +1,188 @@
+//! Closing finished task and submission accounts to reclaim their rent
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Board, Submission, SubmissionStatus, Task, Versioned, Vesting, VestingSchedule},
+};
+use common::{custom, process, process_with, submission, Account, SolBoard, NOW, RENT};
+use solana_program::{pubkey::Pubkey, system_program};
+
+/// An expired task holding 300 lamports of the board's reservation
+fn expired_task(board: &SolBoard) -> Task {
+    let mut task = board.task();
+    task.max_completions = 2;
+    task.num_completions = 1;
+    task.reserved = 300;
+    task.deadline = NOW - 100;
+    task.expired = true;
+    task
+}
+
+fn close_task_accounts(board: &SolBoard, task: &Task) -> Vec<Account> {
+    vec![
+        Account::wallet(board.board.creator, true),
+        board.account(),
+        Account::program(Pubkey::new_unique(), task, RENT),
+    ]
+}
+
+#[test]
+fn close_task_returns_reservation_and_rent() {
+    let board = SolBoard::new(1_000, 300);
+    let mut accounts = close_task_accounts(&board, &expired_task(&board));
+
+    process(&mut accounts, BountyBoardInstruction::CloseTask).unwrap();
+    assert_eq!(accounts[0].lamports, 2 * RENT);
+    assert_eq!(accounts[2].lamports, 0);
+    assert!(accounts[2].data.is_empty());
+    assert_eq!(accounts[2].owner, system_program::id());
+    let updated = accounts[1].load::<Board>();
+    assert_eq!(updated.reserved, 0);
+    assert_eq!(updated.total_pledged, 1_000);
+    assert_eq!(updated.archive.tasks_closed, 1);
+    assert_eq!(updated.archive.completions, 1);
+}
+
+#[test]
+fn close_task_waits_for_task_and_submissions() {
+    let board = SolBoard::new(1_000, 300);
+
+    let mut open = expired_task(&board);
+    open.expired = false;
+    open.deadline = NOW + 100;
+    let mut accounts = close_task_accounts(&board, &open);
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseTask),
+        Err(custom(BountyBoardError::TaskNotFinished))
+    );
+
+    let mut with_submissions = expired_task(&board);
+    with_submissions.open_submissions = 1;
+    let mut accounts = close_task_accounts(&board, &with_submissions);
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseTask),
+        Err(custom(BountyBoardError::TaskHasOpenSubmissions))
+    );
+
+    // Migrated tasks never counted their submissions
+    let mut untracked = expired_task(&board);
+    untracked.submissions_tracked = false;
+    let mut accounts = close_task_accounts(&board, &untracked);
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseTask),
+        Err(custom(BountyBoardError::SubmissionsUntracked))
+    );
+    assert_eq!(accounts[2].lamports, RENT);
+}
+
+fn close_submission_accounts(board: &SolBoard, task: &Task, closed: &Submission) -> Vec<Account> {
+    let task_key = Pubkey::new_unique();
+    let mut closed = closed.clone();
+    closed.task = task_key;
+    vec![
+        Account::wallet(Pubkey::new_unique(), true),
+        board.account(),
+        Account::program(task_key, task, RENT),
+        Account::program(Pubkey::new_unique(), &closed, RENT),
+        Account::wallet(closed.submitter, false),
+    ]
+}
+
+#[test]
+fn close_submission_archives_and_refunds_rent() {
+    let board = SolBoard::new(1_000, 300);
+    let mut task = expired_task(&board);
+    task.open_submissions = 1;
+    let mut approved = submission(Pubkey::default(), SubmissionStatus::Approved);
+    approved.credit_reward(300);
+    approved.payouts[0].claimed = true;
+    let mut accounts = close_submission_accounts(&board, &task, &approved);
+
+    process(&mut accounts, BountyBoardInstruction::CloseSubmission).unwrap();
+    assert_eq!(accounts[4].lamports, 2 * RENT);
+    assert_eq!(accounts[3].lamports, 0);
+    assert_eq!(accounts[3].owner, system_program::id());
+    assert_eq!(accounts[2].load::<Task>().open_submissions, 0);
+    let updated = accounts[1].load::<Board>();
+    assert_eq!(updated.archive.submissions_closed, 1);
+    assert_eq!(updated.archive.submissions_approved, 1);
+}
+
+#[test]
+fn close_submission_before_task_finishes_is_left_to_submitter() {
+    let board = SolBoard::new(1_000, 300);
+    let mut task = board.task();
+    task.open_submissions = 1;
+    let rejected = submission(Pubkey::default(), SubmissionStatus::Rejected);
+    let mut accounts = close_submission_accounts(&board, &task, &rejected);
+
+    // Others could cut short the submitter's chance to dispute
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseSubmission),
+        Err(custom(BountyBoardError::TaskNotFinished))
+    );
+
+    accounts[0] = Account::wallet(rejected.submitter, true);
+    let order = [0, 1, 2, 3, 0];
+    process_with(&mut accounts, &order, BountyBoardInstruction::CloseSubmission).unwrap();
+    assert_eq!(accounts[0].lamports, 2 * RENT);
+    assert_eq!(accounts[1].load::<Board>().archive.submissions_approved, 0);
+}
+
+#[test]
+fn close_submission_requires_settled_funds() {
+    let board = SolBoard::new(1_000, 300);
+    let mut task = expired_task(&board);
+    task.open_submissions = 1;
+
+    let mut unclaimed = submission(Pubkey::default(), SubmissionStatus::Approved);
+    unclaimed.credit_reward(300);
+    let mut accounts = close_submission_accounts(&board, &task, &unclaimed);
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseSubmission),
+        Err(custom(BountyBoardError::SubmissionNotFinalized))
+    );
+
+    let mut deposited = submission(Pubkey::default(), SubmissionStatus::Rejected);
+    deposited.deposit = 50;
+    let mut accounts = close_submission_accounts(&board, &task, &deposited);
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseSubmission),
+        Err(custom(BountyBoardError::SubmissionNotFinalized))
+    );
+}
+
+#[test]
+fn close_submission_waits_for_vesting_release() {
+    let board = SolBoard::new(1_000, 300);
+    let mut task = expired_task(&board);
+    task.open_submissions = 1;
+    task.vesting = Some(VestingSchedule {
+        cliff: 0,
+        duration: 100,
+    });
+    let approved = submission(Pubkey::default(), SubmissionStatus::Approved);
+    let mut accounts = close_submission_accounts(&board, &task, &approved);
+
+    let mut vesting = Vesting::default();
+    vesting.header = Vesting::header();
+    vesting.is_initialized = true;
+    vesting.submission = accounts[3].key;
+    vesting.total = 300;
+    vesting.released = 100;
+    accounts.push(Account::program(Pubkey::new_unique(), &vesting, RENT));
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::CloseSubmission),
+        Err(custom(BountyBoardError::SubmissionNotFinalized))
+    );
+
+    vesting.released = 300;
+    accounts[5] = Account::program(accounts[5].key, &vesting, RENT);
+    process(&mut accounts, BountyBoardInstruction::CloseSubmission).unwrap();
+    assert_eq!(accounts[3].lamports, 0);
+}
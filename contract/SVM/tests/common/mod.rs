@@ -0,0 +1,265 @@
+//! An in-memory harness for processor tests of SOL boards, whose funds
+//! move without cross-program invocations. Accounts are plain `AccountInfo`s
+//! and the clock and rent sysvars are stubbed, so the runtime's ownership,
+//! rent and balance rules are not enforced: writes to accounts the program
+//! does not own succeed here. These tests cover the program's bookkeeping,
+//! not its security against malicious accounts.
+
+#![allow(clippy::field_reassign_with_default)]
+// Every test crate uses a different part of the harness
+#![allow(dead_code)]
+
+use borsh::BorshSerialize;
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    processor::Processor,
+    state::{Board, ProgramConfig, RewardAsset, Submission, SubmissionStatus, Task, Versioned},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+    program_error::ProgramError,
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+    system_program,
+};
+
+pub const NOW: i64 = 1_000_000;
+pub const RENT: u64 = 1_000_000;
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+}
+
+pub fn program_id() -> Pubkey {
+    Pubkey::new_from_array([7; 32])
+}
+
+pub fn find_pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &program_id()).0
+}
+
+pub struct Account {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub is_signer: bool,
+}
+
+impl Account {
+    pub fn wallet(key: Pubkey, is_signer: bool) -> Self {
+        Self {
+            key,
+            owner: system_program::id(),
+            lamports: RENT,
+            data: Vec::new(),
+            is_signer,
+        }
+    }
+
+    pub fn program<T: Versioned>(key: Pubkey, state: &T, lamports: u64) -> Self {
+        let mut data = state.try_to_vec().unwrap();
+        data.resize(T::MAX_LEN, 0);
+        Self {
+            key,
+            owner: program_id(),
+            lamports,
+            data,
+            is_signer: false,
+        }
+    }
+
+    /// A zeroed account allocated for a new `T`
+    pub fn empty<T: Versioned>(key: Pubkey) -> Self {
+        Self {
+            key,
+            owner: program_id(),
+            lamports: RENT,
+            data: vec![0; T::MAX_LEN],
+            is_signer: false,
+        }
+    }
+
+    pub fn load<T: Versioned>(&self) -> T {
+        T::load(&self.data).unwrap()
+    }
+}
+
+/// An account laid out as the runtime serializes it: `AccountInfo::realloc`
+/// reads the original data length before the key and writes the new one
+/// before the data, which has room to grow
+#[repr(C)]
+struct Serialized {
+    original_data_len: u32,
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    is_signer: bool,
+    is_writable: bool,
+    data: Vec<u64>,
+}
+
+impl Serialized {
+    fn new(account: &Account, is_writable: bool) -> Self {
+        let len = account.data.len();
+        let mut serialized = Self {
+            original_data_len: len as u32,
+            key: account.key,
+            owner: account.owner,
+            lamports: account.lamports,
+            is_signer: account.is_signer,
+            is_writable,
+            data: vec![0; 1 + (len + MAX_PERMITTED_DATA_INCREASE).div_ceil(8)],
+        };
+        serialized.data[0] = len as u64;
+        serialized.bytes()[8..8 + len].copy_from_slice(&account.data);
+        serialized
+    }
+
+    fn bytes(&mut self) -> &mut [u8] {
+        let len = self.data.len() * 8;
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut u8, len) }
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        let len = self.data[0] as usize;
+        let data = unsafe {
+            std::slice::from_raw_parts_mut((self.data.as_mut_ptr() as *mut u8).add(8), len)
+        };
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+
+    fn write_back(mut self, account: &mut Account) {
+        let len = self.data[0] as usize;
+        account.data = self.bytes()[8..8 + len].to_vec();
+        account.lamports = self.lamports;
+        account.owner = self.owner;
+    }
+}
+
+/// Runs an instruction with `accounts` in the order of `order`, which may
+/// repeat an account, after the program config
+pub fn process_with(
+    accounts: &mut [Account],
+    order: &[usize],
+    instruction: BountyBoardInstruction,
+) -> ProgramResult {
+    set_syscall_stubs(Box::new(Stubs));
+
+    let config_key = find_pda(&[b"config"]);
+    let mut program_config = ProgramConfig::default();
+    program_config.header = ProgramConfig::header();
+    program_config.is_initialized = true;
+    let config = Account::program(config_key, &program_config, RENT);
+
+    let mut config = Serialized::new(&config, false);
+    let mut serialized: Vec<Serialized> = accounts
+        .iter()
+        .map(|account| Serialized::new(account, true))
+        .collect();
+    let mut data = vec![0];
+    data.extend(instruction.try_to_vec().unwrap());
+
+    let result = {
+        let infos: Vec<AccountInfo> = serialized.iter_mut().map(Serialized::info).collect();
+        let mut ordered = vec![config.info()];
+        ordered.extend(order.iter().map(|index| infos[*index].clone()));
+        Processor::process(&program_id(), &ordered, &data)
+    };
+
+    for (serialized, account) in serialized.into_iter().zip(accounts.iter_mut()) {
+        serialized.write_back(account);
+    }
+    result
+}
+
+pub fn process(accounts: &mut [Account], instruction: BountyBoardInstruction) -> ProgramResult {
+    let order: Vec<usize> = (0..accounts.len()).collect();
+    process_with(accounts, &order, instruction)
+}
+
+/// A SOL board holding `pledged` lamports in its vault, `reserved` of them
+/// for its tasks
+pub struct SolBoard {
+    pub key: Pubkey,
+    pub vault: Pubkey,
+    pub board: Board,
+}
+
+impl SolBoard {
+    pub fn new(pledged: u64, reserved: u64) -> Self {
+        let key = Pubkey::new_unique();
+        let vault = find_pda(&[b"vault", key.as_ref()]);
+        let mut board = Board::default();
+        board.header = Board::header();
+        board.is_initialized = true;
+        board.creator = Pubkey::new_unique();
+        board.reward_asset = RewardAsset::Sol;
+        board.vault = vault;
+        board.total_pledged = pledged;
+        board.reserved = reserved;
+        Self { key, vault, board }
+    }
+
+    pub fn account(&self) -> Account {
+        Account::program(self.key, &self.board, RENT)
+    }
+
+    pub fn vault_account(&self) -> Account {
+        Account {
+            key: self.vault,
+            owner: program_id(),
+            lamports: RENT + self.board.total_pledged,
+            data: Vec::new(),
+            is_signer: false,
+        }
+    }
+
+    pub fn task(&self) -> Task {
+        let mut task = Task::default();
+        task.board = self.key;
+        task.creator = self.board.creator;
+        task.reward_amount = 300;
+        task.max_completions = 1;
+        task
+    }
+}
+
+pub fn submission(task_key: Pubkey, status: SubmissionStatus) -> Submission {
+    let mut submission = Submission::default();
+    submission.task = task_key;
+    submission.submitter = Pubkey::new_unique();
+    submission.status = status;
+    submission
+}
+
+pub fn custom(error: BountyBoardError) -> ProgramError {
+    error.into()
+}
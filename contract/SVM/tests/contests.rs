@@ -0,0 +1,136 @@
+//! Ranking and finalizing contests on SOL boards
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Board, Submission, SubmissionStatus, Task, TaskKind},
+};
+use common::{custom, process_with, submission, Account, SolBoard, NOW, RENT};
+use solana_program::pubkey::Pubkey;
+
+/// A contest with a 1001 lamport prize pool over three places, its
+/// reviewer, and `entries` pending entries
+fn contest_accounts(board: &SolBoard, entries: usize) -> Vec<Account> {
+    let reviewer = board.board.creator;
+    let task_key = Pubkey::new_unique();
+    let mut task = board.task();
+    task.kind = TaskKind::Contest;
+    task.reward_amount = 1_001;
+    task.deadline = NOW - 10;
+    task.review_grace_period = 100;
+    task.prize_shares_bps = vec![5_000, 3_000, 2_000];
+    task.reserved = 1_001;
+    task.reviewers = vec![reviewer];
+
+    let mut accounts = vec![
+        Account::wallet(reviewer, true),
+        board.account(),
+        Account::program(task_key, &task, RENT),
+    ];
+    for _ in 0..entries {
+        let entry = submission(task_key, SubmissionStatus::Pending);
+        accounts.push(Account::program(Pubkey::new_unique(), &entry, RENT));
+    }
+    accounts
+}
+
+#[test]
+fn finalize_contest_pays_places_and_returns_the_rest() {
+    let board = SolBoard::new(2_000, 1_001);
+    let mut accounts = contest_accounts(&board, 3);
+
+    // The third entry goes unranked
+    let ranking = [0, 2, 4, 3];
+    process_with(&mut accounts, &ranking, BountyBoardInstruction::SubmitRanking).unwrap();
+    let ranked = accounts[2].load::<Task>().ranking;
+    assert_eq!(ranked, vec![accounts[4].key, accounts[3].key]);
+
+    process_with(&mut accounts, &[0, 1, 2, 4, 3], BountyBoardInstruction::FinalizeContest)
+        .unwrap();
+    let first = accounts[4].load::<Submission>();
+    assert_eq!(first.status, SubmissionStatus::Approved);
+    assert_eq!(first.payouts[0].amount, 500);
+    assert_eq!(accounts[3].load::<Submission>().payouts[0].amount, 300);
+    assert_eq!(accounts[5].load::<Submission>().status, SubmissionStatus::Pending);
+
+    let task = accounts[2].load::<Task>();
+    assert!(task.completed);
+    assert_eq!(task.reserved, 0);
+    assert_eq!(task.num_completions, 2);
+    // The third place and the rounding dust stay with the board
+    let updated = accounts[1].load::<Board>();
+    assert_eq!(updated.reserved, 0);
+    assert_eq!(updated.total_pledged, 1_200);
+
+    assert_eq!(
+        process_with(&mut accounts, &[0, 1, 2, 4, 3], BountyBoardInstruction::FinalizeContest),
+        Err(custom(BountyBoardError::TaskAlreadyCompleted))
+    );
+}
+
+#[test]
+fn finalize_contest_requires_the_ranked_entries_in_order() {
+    let board = SolBoard::new(2_000, 1_001);
+    let mut accounts = contest_accounts(&board, 2);
+    process_with(&mut accounts, &[0, 2, 3, 4], BountyBoardInstruction::SubmitRanking).unwrap();
+
+    assert_eq!(
+        process_with(&mut accounts, &[0, 1, 2, 4, 3], BountyBoardInstruction::FinalizeContest),
+        Err(custom(BountyBoardError::InvalidRanking))
+    );
+}
+
+#[test]
+fn submit_ranking_rejects_invalid_rankings() {
+    let board = SolBoard::new(2_000, 1_001);
+    let mut accounts = contest_accounts(&board, 4);
+    let rank = |accounts: &mut [Account], order: &[usize]| {
+        process_with(accounts, order, BountyBoardInstruction::SubmitRanking)
+    };
+
+    // Duplicate entries
+    assert_eq!(
+        rank(&mut accounts, &[0, 2, 3, 3]),
+        Err(custom(BountyBoardError::InvalidRanking))
+    );
+    // More entries than places
+    assert_eq!(
+        rank(&mut accounts, &[0, 2, 3, 4, 5, 6]),
+        Err(custom(BountyBoardError::InvalidRanking))
+    );
+    // Entries no longer pending
+    let mut rejected = accounts[3].load::<Submission>();
+    rejected.status = SubmissionStatus::Rejected;
+    accounts[3] = Account::program(accounts[3].key, &rejected, RENT);
+    assert_eq!(
+        rank(&mut accounts, &[0, 2, 3]),
+        Err(custom(BountyBoardError::InvalidRanking))
+    );
+    // Entries of another task
+    let other = submission(Pubkey::new_unique(), SubmissionStatus::Pending);
+    accounts[3] = Account::program(accounts[3].key, &other, RENT);
+    assert_eq!(
+        rank(&mut accounts, &[0, 2, 3]),
+        Err(custom(BountyBoardError::InvalidRanking))
+    );
+
+    rank(&mut accounts, &[0, 2, 4, 5, 6]).unwrap();
+}
+
+#[test]
+fn contests_are_ranked_after_their_deadline() {
+    let board = SolBoard::new(2_000, 1_001);
+    let mut accounts = contest_accounts(&board, 1);
+    let mut task = accounts[2].load::<Task>();
+    task.deadline = NOW + 10;
+    accounts[2] = Account::program(accounts[2].key, &task, RENT);
+
+    assert_eq!(
+        process_with(&mut accounts, &[0, 2, 3], BountyBoardInstruction::SubmitRanking),
+        Err(custom(BountyBoardError::ContestNotEnded))
+    );
+}
@@ -0,0 +1,126 @@
+//! Resolution of disputes and their bonds on SOL boards
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Board, Dispute, Submission, SubmissionStatus, Versioned},
+};
+use common::{custom, find_pda, process, submission, Account, SolBoard, RENT};
+use solana_program::pubkey::Pubkey;
+
+fn dispute_accounts(board: &SolBoard, forfeit: Account) -> Vec<Account> {
+    let arbiter = Pubkey::new_unique();
+    let mut board_state = board.board.clone();
+    board_state.arbiters = vec![arbiter];
+    board_state.dispute_bond = 100;
+
+    let task_key = Pubkey::new_unique();
+    let submission_key = Pubkey::new_unique();
+    let disputed = submission(task_key, SubmissionStatus::Disputed);
+    let mut dispute = Dispute::default();
+    dispute.header = Dispute::header();
+    dispute.is_initialized = true;
+    dispute.submission = submission_key;
+    dispute.submitter = disputed.submitter;
+    dispute.bond = 100;
+    let dispute_key = find_pda(&[b"dispute", submission_key.as_ref()]);
+
+    vec![
+        Account::wallet(arbiter, true),
+        Account::program(board.key, &board_state, RENT),
+        Account::program(task_key, &board.task(), RENT),
+        Account::program(submission_key, &disputed, RENT),
+        Account::program(dispute_key, &dispute, RENT + 100),
+        Account::wallet(disputed.submitter, false),
+        forfeit,
+    ]
+}
+
+#[test]
+fn upheld_dispute_pledges_bond_to_sol_vault() {
+    let board = SolBoard::new(1_000, 0);
+    let mut accounts = dispute_accounts(&board, board.vault_account());
+
+    process(
+        &mut accounts,
+        BountyBoardInstruction::ResolveDispute {
+            overturn: false,
+            comment: "Upheld".to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(accounts[4].lamports, RENT);
+    assert_eq!(accounts[6].lamports, RENT + 1_100);
+    assert_eq!(accounts[5].lamports, RENT);
+    assert_eq!(accounts[1].load::<Board>().total_pledged, 1_100);
+    assert_eq!(accounts[3].load::<Submission>().status, SubmissionStatus::Rejected);
+    assert!(accounts[4].load::<Dispute>().resolved);
+}
+
+#[test]
+fn upheld_dispute_rejects_other_forfeit_accounts() {
+    let board = SolBoard::new(1_000, 0);
+    let forfeit = Account::wallet(Pubkey::new_unique(), false);
+    let mut accounts = dispute_accounts(&board, forfeit);
+
+    assert_eq!(
+        process(
+            &mut accounts,
+            BountyBoardInstruction::ResolveDispute {
+                overturn: false,
+                comment: "Upheld".to_string(),
+            },
+        ),
+        Err(custom(BountyBoardError::InvalidDepositAccount))
+    );
+}
+
+fn overturn() -> BountyBoardInstruction {
+    BountyBoardInstruction::ResolveDispute {
+        overturn: true,
+        comment: "Overturned".to_string(),
+    }
+}
+
+#[test]
+fn overturned_dispute_refunds_bond_and_credits_reward() {
+    let board = SolBoard::new(1_000, 300);
+    let mut accounts = dispute_accounts(&board, board.vault_account());
+    let mut task = board.task();
+    task.reserved = 300;
+    accounts[2] = Account::program(accounts[2].key, &task, RENT);
+
+    process(&mut accounts, overturn()).unwrap();
+    assert_eq!(accounts[4].lamports, RENT);
+    assert_eq!(accounts[5].lamports, RENT + 100);
+    let approved = accounts[3].load::<Submission>();
+    assert_eq!(approved.status, SubmissionStatus::Approved);
+    assert_eq!(approved.payouts[0].amount, 300);
+    let updated = accounts[1].load::<Board>();
+    assert_eq!(updated.reserved, 0);
+    assert_eq!(updated.total_pledged, 700);
+}
+
+#[test]
+fn overturned_dispute_of_completed_task_only_refunds_bond() {
+    let board = SolBoard::new(1_000, 0);
+    let mut accounts = dispute_accounts(&board, board.vault_account());
+    let mut task = board.task();
+    task.num_completions = 1;
+    task.completed = true;
+    accounts[2] = Account::program(accounts[2].key, &task, RENT);
+
+    process(&mut accounts, overturn()).unwrap();
+    assert_eq!(accounts[4].lamports, RENT);
+    assert_eq!(accounts[5].lamports, RENT + 100);
+    let rejected = accounts[3].load::<Submission>();
+    assert_eq!(rejected.status, SubmissionStatus::Rejected);
+    assert!(rejected.payouts.is_empty());
+    let dispute = accounts[4].load::<Dispute>();
+    assert!(dispute.resolved && dispute.overturned);
+    assert_eq!(accounts[1].load::<Board>().total_pledged, 1_000);
+}
@@ -0,0 +1,62 @@
+//! Expiry of past-deadline tasks and refunds of their deposits
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Board, DepositKind, Submission, SubmissionStatus, Task},
+};
+use common::{custom, process_with, submission, Account, SolBoard, NOW, RENT};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn expire_task_refunds_deposits_and_rejects_leftovers() {
+    let board = SolBoard::new(1_000, 300);
+    let task_key = Pubkey::new_unique();
+    let mut task = board.task();
+    task.deadline = NOW - 100;
+    task.deposit_kind = DepositKind::Lamports;
+    task.submission_deposit = 50;
+    task.reserved = 300;
+
+    let mut pending = submission(task_key, SubmissionStatus::Pending);
+    pending.deposit = 50;
+    let mut leftover = submission(task_key, SubmissionStatus::Pending);
+    leftover.deposit = 50;
+
+    let mut accounts = vec![
+        board.account(),
+        Account::program(task_key, &task, RENT),
+        Account::program(Pubkey::new_unique(), &pending, RENT + 50),
+        Account::wallet(pending.submitter, false),
+        Account::program(Pubkey::new_unique(), &leftover, RENT + 50),
+        Account::wallet(leftover.submitter, false),
+    ];
+
+    process_with(&mut accounts, &[0, 1, 2, 3], BountyBoardInstruction::ExpireTask).unwrap();
+    let expired = accounts[1].load::<Task>();
+    assert!(expired.expired);
+    assert_eq!(expired.reserved, 0);
+    let updated = accounts[0].load::<Board>();
+    assert_eq!(updated.reserved, 0);
+    assert_eq!(updated.total_pledged, 1_000);
+    let rejected = accounts[2].load::<Submission>();
+    assert_eq!(rejected.status, SubmissionStatus::Rejected);
+    assert_eq!(rejected.deposit, 0);
+    assert_eq!(accounts[2].lamports, RENT);
+    assert_eq!(accounts[3].lamports, RENT + 50);
+
+    // Expiring again resolves a submission left out before
+    process_with(&mut accounts, &[0, 1, 4, 5], BountyBoardInstruction::ExpireTask).unwrap();
+    assert_eq!(accounts[4].load::<Submission>().status, SubmissionStatus::Rejected);
+    assert_eq!(accounts[5].lamports, RENT + 50);
+    assert_eq!(accounts[0].load::<Board>().total_pledged, 1_000);
+
+    assert_eq!(
+        process_with(&mut accounts, &[0, 1, 4, 5], BountyBoardInstruction::ExpireTask),
+        Err(custom(BountyBoardError::SubmissionNotPending))
+    );
+}
@@ -0,0 +1,172 @@
+//! Rolling recurring tasks into their next cycle, and ending them
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::{Board, Recurrence, RewardChoice, Submission, SubmissionStatus, Task},
+};
+use common::{custom, process, process_with, submission, Account, SolBoard, NOW, RENT};
+use solana_program::{pubkey::Pubkey, system_program};
+
+/// A recurring task over three cycles whose first cycle ended and paid out
+fn recurring_task(board: &SolBoard) -> Task {
+    let mut task = board.task();
+    task.recurrence = Some(Recurrence {
+        period: 1_000,
+        cycles: 3,
+    });
+    task.deadline = NOW - 10;
+    task.num_completions = 1;
+    task.completed = true;
+    task
+}
+
+fn roll_accounts(board: &SolBoard, task: &Task) -> Vec<Account> {
+    vec![
+        board.account(),
+        Account::program(Pubkey::new_unique(), task, RENT),
+    ]
+}
+
+#[test]
+fn roll_task_opens_and_reserves_the_next_cycle() {
+    let board = SolBoard::new(1_000, 0);
+    let mut accounts = roll_accounts(&board, &recurring_task(&board));
+
+    process(&mut accounts, BountyBoardInstruction::RollTask).unwrap();
+    let rolled = accounts[1].load::<Task>();
+    assert_eq!(rolled.cycle, 1);
+    assert_eq!(rolled.deadline, NOW + 990);
+    assert_eq!(rolled.num_completions, 0);
+    assert!(!rolled.completed);
+    assert_eq!(rolled.reserved, 300);
+    let updated = accounts[0].load::<Board>();
+    assert_eq!(updated.reserved, 300);
+    assert_eq!(updated.total_pledged, 1_000);
+
+    // The second cycle has not ended yet
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::RollTask),
+        Err(custom(BountyBoardError::CycleNotEnded))
+    );
+}
+
+#[test]
+fn roll_task_releases_unfilled_completions() {
+    let board = SolBoard::new(1_000, 300);
+    let mut task = recurring_task(&board);
+    task.num_completions = 0;
+    task.completed = false;
+    task.reserved = 300;
+    let mut accounts = roll_accounts(&board, &task);
+
+    process(&mut accounts, BountyBoardInstruction::RollTask).unwrap();
+    assert_eq!(accounts[1].load::<Task>().reserved, 300);
+    assert_eq!(accounts[0].load::<Board>().reserved, 300);
+}
+
+#[test]
+fn roll_task_waits_for_the_review_grace_period() {
+    let board = SolBoard::new(1_000, 0);
+    let mut task = recurring_task(&board);
+    task.review_grace_period = 100;
+    let mut accounts = roll_accounts(&board, &task);
+
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::RollTask),
+        Err(custom(BountyBoardError::CycleNotEnded))
+    );
+}
+
+#[test]
+fn roll_task_stops_after_the_last_cycle() {
+    let board = SolBoard::new(1_000, 0);
+    let mut task = recurring_task(&board);
+    task.cycle = 2;
+    let mut accounts = roll_accounts(&board, &task);
+
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::RollTask),
+        Err(custom(BountyBoardError::NoCyclesRemaining))
+    );
+}
+
+#[test]
+fn expire_task_ends_a_recurrence_the_board_cannot_fund() {
+    // Everything left is reserved elsewhere
+    let board = SolBoard::new(1_000, 1_000);
+    let mut accounts = roll_accounts(&board, &recurring_task(&board));
+
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::RollTask),
+        Err(custom(BountyBoardError::InsufficientFunds))
+    );
+    process(&mut accounts, BountyBoardInstruction::ExpireTask).unwrap();
+    assert!(accounts[1].load::<Task>().expired);
+
+    // A fundable recurrence keeps running
+    let board = SolBoard::new(1_000, 0);
+    let mut accounts = roll_accounts(&board, &recurring_task(&board));
+    assert_eq!(
+        process(&mut accounts, BountyBoardInstruction::ExpireTask),
+        Err(custom(BountyBoardError::TaskNotExpired))
+    );
+}
+
+#[test]
+fn submissions_of_earlier_cycles_are_only_rejected() {
+    let board = SolBoard::new(1_000, 0);
+    let mut task = recurring_task(&board);
+    task.cycle = 1;
+    task.deadline = NOW + 990;
+    task.num_completions = 0;
+    task.completed = false;
+    task.reserved = 300;
+    task.approval_threshold = 1;
+    task.reviewers = vec![board.board.creator];
+
+    let task_key = Pubkey::new_unique();
+    let stale = submission(task_key, SubmissionStatus::Pending);
+    let current = {
+        let mut current = submission(task_key, SubmissionStatus::Pending);
+        current.cycle = 1;
+        current
+    };
+    let mut accounts = vec![
+        Account::wallet(board.board.creator, true),
+        board.account(),
+        Account::program(task_key, &task, RENT),
+        Account::program(Pubkey::new_unique(), &stale, RENT),
+        Account::wallet(Pubkey::new_unique(), false),
+        Account::wallet(system_program::id(), false),
+        Account::program(Pubkey::new_unique(), &current, RENT),
+    ];
+
+    assert_eq!(
+        process_with(
+            &mut accounts,
+            &[0, 1, 2, 3, 4, 5],
+            BountyBoardInstruction::ReviewSubmission {
+                status: 1,
+                review_comment: "Late".to_string(),
+                reward: RewardChoice::Full,
+            },
+        ),
+        Err(custom(BountyBoardError::SubmissionFromPastCycle))
+    );
+
+    // Expiring the running task only rejects earlier cycles' submissions
+    assert_eq!(
+        process_with(&mut accounts, &[1, 2, 6], BountyBoardInstruction::ExpireTask),
+        Err(custom(BountyBoardError::TaskNotExpired))
+    );
+    process_with(&mut accounts, &[1, 2, 3], BountyBoardInstruction::ExpireTask).unwrap();
+    assert_eq!(accounts[3].load::<Submission>().status, SubmissionStatus::Rejected);
+    let running = accounts[2].load::<Task>();
+    assert!(!running.expired);
+    assert_eq!(running.reserved, 300);
+}
@@ -0,0 +1,42 @@
+//! Reservations of board funds for tasks, which keep a board from closing
+
+#![allow(clippy::field_reassign_with_default)]
+
+mod common;
+
+use bounty_board::{
+    error::BountyBoardError,
+    instruction::BountyBoardInstruction,
+    state::Board,
+};
+use common::{custom, find_pda, process_with, Account, SolBoard, RENT};
+use solana_program::system_program;
+
+#[test]
+fn close_board_returns_pledges_once_nothing_is_reserved() {
+    let mut board = SolBoard::new(1_000, 300);
+    let creator = board.board.creator;
+    let authority = find_pda(&[b"authority", board.key.as_ref()]);
+    let mut accounts = vec![
+        Account::wallet(creator, true),
+        board.account(),
+        board.vault_account(),
+        Account::wallet(system_program::id(), false),
+        Account::wallet(authority, false),
+    ];
+    let order = [0, 1, 2, 0, 3, 4];
+
+    assert_eq!(
+        process_with(&mut accounts, &order, BountyBoardInstruction::CloseBoard),
+        Err(custom(BountyBoardError::BoardHasReservedFunds))
+    );
+
+    board.board.reserved = 0;
+    accounts[1] = board.account();
+    process_with(&mut accounts, &order, BountyBoardInstruction::CloseBoard).unwrap();
+    assert_eq!(accounts[0].lamports, RENT + 1_000);
+    assert_eq!(accounts[2].lamports, RENT);
+    let closed = accounts[1].load::<Board>();
+    assert!(closed.closed);
+    assert_eq!(closed.total_pledged, 0);
+}